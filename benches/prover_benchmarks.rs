@@ -17,17 +17,25 @@
 //! cargo bench --bench prover_benchmarks -- tautologies
 //! cargo bench --bench prover_benchmarks -- worst_case_scenarios
 //! cargo bench --bench prover_benchmarks -- prove_result_variants
+//! cargo bench --bench prover_benchmarks -- contingent_propositions
+//! cargo bench --bench prover_benchmarks -- expansion_strategies
+//! cargo bench --bench prover_benchmarks -- decision_engines
 //! ```
 
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use raa_tt::{
     bi_implication::BiImplication,
+    cdcl::CdclSolver,
     conjunction::Conjunction,
+    decider::Decider,
     disjunction::Disjunction,
+    dpll::DpllSolver,
     implication::Implication,
     negation::Negation,
     proposition::Proposition,
     prover::{ProveResult, Prover},
+    strategy::ExpansionStrategy,
+    table_generator::TableGenerator,
 };
 
 /// Simple propositions with basic operations
@@ -260,6 +268,109 @@ fn prove_result_variants() -> Vec<(&'static str, Proposition, ProveResult)> {
     ]
 }
 
+/// Contingent propositions of growing size
+///
+/// Deciding a contingent proposition requires an assignment that makes it false and one that makes
+/// it true.
+fn contingent_propositions() -> Vec<(&'static str, Proposition)> {
+    vec![
+        ("deep_conjunction_8", create_deep_conjunction(8)),
+        ("deep_disjunction_8", create_deep_disjunction(8)),
+        ("deep_implication_6", create_deep_implication_chain(6)),
+        (
+            "disjunction_of_conjunctions_4",
+            create_disjunction_of_conjunctions(4),
+        ),
+    ]
+}
+
+/// Propositions whose truth trees depend on the expansion strategy
+fn strategy_propositions() -> Vec<(&'static str, Proposition)> {
+    let mut propositions = worst_case_scenarios();
+    propositions.push((
+        "disjunction_of_conjunctions_4",
+        create_disjunction_of_conjunctions(4),
+    ));
+    propositions.push(("hypothetical_syllogism_8", create_hypothetical_syllogism(8)));
+    propositions.push((
+        "disjunctions_before_refutation_4",
+        create_disjunctions_before_refutation(4),
+    ));
+    propositions
+}
+
+/// Create the contradiction `(a0 | b0) & ... & (an-1 | bn-1) & (!a0 & !b0)`
+///
+/// Decomposing the disjunctions first creates 2^n branches that are all closed by the last
+/// conjunction.
+fn create_disjunctions_before_refutation(count: usize) -> Proposition {
+    let disjunction = |i: usize| {
+        Proposition::Disjunction(Disjunction {
+            left: Box::new(Proposition::Atom(format!("a{}", i))),
+            right: Box::new(Proposition::Atom(format!("b{}", i))),
+        })
+    };
+    let disjunctions = (1..count).fold(disjunction(0), |left, i| {
+        Proposition::Conjunction(Conjunction {
+            left: Box::new(left),
+            right: Box::new(disjunction(i)),
+        })
+    });
+    Proposition::Conjunction(Conjunction {
+        left: Box::new(disjunctions),
+        right: Box::new(Proposition::Conjunction(Conjunction {
+            left: Box::new(Proposition::Negation(Negation {
+                inner: Box::new(Proposition::Atom("a0".to_string())),
+            })),
+            right: Box::new(Proposition::Negation(Negation {
+                inner: Box::new(Proposition::Atom("b0".to_string())),
+            })),
+        })),
+    })
+}
+
+/// Create the tautology `(p0 -> p1) & ... & (pn-1 -> pn) -> (p0 -> pn)`
+fn create_hypothetical_syllogism(length: usize) -> Proposition {
+    let link = |i: usize| {
+        Proposition::Implication(Implication {
+            left: Box::new(Proposition::Atom(format!("p{}", i))),
+            right: Box::new(Proposition::Atom(format!("p{}", i + 1))),
+        })
+    };
+    let chain = (1..length).fold(link(0), |left, i| {
+        Proposition::Conjunction(Conjunction {
+            left: Box::new(left),
+            right: Box::new(link(i)),
+        })
+    });
+    Proposition::Implication(Implication {
+        left: Box::new(chain),
+        right: Box::new(Proposition::Implication(Implication {
+            left: Box::new(Proposition::Atom("p0".to_string())),
+            right: Box::new(Proposition::Atom(format!("p{}", length))),
+        })),
+    })
+}
+
+/// Create a disjunction of `count` conjunctions of two variables each
+fn create_disjunction_of_conjunctions(count: usize) -> Proposition {
+    (1..count).fold(
+        Proposition::Conjunction(Conjunction {
+            left: Box::new(Proposition::Atom("a0".to_string())),
+            right: Box::new(Proposition::Atom("b0".to_string())),
+        }),
+        |left, i| {
+            Proposition::Disjunction(Disjunction {
+                left: Box::new(left),
+                right: Box::new(Proposition::Conjunction(Conjunction {
+                    left: Box::new(Proposition::Atom(format!("a{}", i))),
+                    right: Box::new(Proposition::Atom(format!("b{}", i))),
+                })),
+            })
+        },
+    )
+}
+
 /// Create a deeply nested conjunction
 fn create_deep_conjunction(depth: usize) -> Proposition {
     if depth == 0 {
@@ -383,6 +494,81 @@ fn bench_prove_result_variants(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark contingent propositions
+fn bench_contingent_propositions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("prover_contingent_propositions");
+
+    for (name, proposition) in contingent_propositions() {
+        group.bench_with_input(BenchmarkId::new("prove", name), &proposition, |b, prop| {
+            b.iter(|| {
+                let prover = Prover::new();
+                let result = prover.prove(prop).unwrap();
+                assert_eq!(result, ProveResult::Contingent);
+                result
+            });
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmark the expansion strategies of the prover
+///
+/// The number of nodes of the truth trees is printed for each strategy, too.
+fn bench_expansion_strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("prover_expansion_strategies");
+
+    let strategies = [
+        ExpansionStrategy::InOrder,
+        ExpansionStrategy::AlphaFirst,
+        ExpansionStrategy::SmallestFirst,
+        ExpansionStrategy::MostConstrainedFirst,
+    ];
+    for (name, proposition) in strategy_propositions() {
+        for strategy in &strategies {
+            let proof = Prover::with_strategy(strategy.clone())
+                .prove_with_tableaux(&proposition)
+                .unwrap();
+            let nodes: usize = proof.tableaux.iter().map(|t| t.nodes.len()).sum();
+            println!("{name} with {}: {nodes} nodes", strategy.name());
+
+            group.bench_with_input(
+                BenchmarkId::new(strategy.name(), name),
+                &proposition,
+                |b, prop| {
+                    b.iter(|| {
+                        let prover = Prover::with_strategy(strategy.clone());
+                        prover.prove(prop).unwrap()
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+/// Benchmark the classification of propositions by the different decision engines
+fn bench_decision_engines(c: &mut Criterion) {
+    let mut group = c.benchmark_group("prover_decision_engines");
+
+    let engines: [(&str, Box<dyn Decider>); 4] = [
+        ("tableau", Box::new(Prover::new())),
+        ("truth_table", Box::new(TableGenerator::new())),
+        ("dpll", Box::new(DpllSolver::new())),
+        ("cdcl", Box::new(CdclSolver::new())),
+    ];
+    for (name, proposition) in strategy_propositions() {
+        for (engine, decider) in &engines {
+            group.bench_with_input(BenchmarkId::new(*engine, name), &proposition, |b, prop| {
+                b.iter(|| decider.classify(prop).unwrap());
+            });
+        }
+    }
+
+    group.finish();
+}
+
 /// Benchmark prover creation overhead
 fn bench_prover_creation(c: &mut Criterion) {
     c.bench_function("prover_creation", |b| {
@@ -397,6 +583,9 @@ criterion_group!(
     bench_complex_propositions,
     bench_tautologies,
     bench_worst_case_scenarios,
-    bench_prove_result_variants
+    bench_prove_result_variants,
+    bench_contingent_propositions,
+    bench_expansion_strategies,
+    bench_decision_engines
 );
 criterion_main!(prover_benches);
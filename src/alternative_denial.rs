@@ -0,0 +1,15 @@
+use crate::proposition::Proposition;
+use std::fmt::{Debug, Display, Error, Formatter};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+/// AlternativeDenial struct represents a binary logical alternative denial (NAND) operation.
+pub struct AlternativeDenial {
+    pub left: Box<Proposition>,
+    pub right: Box<Proposition>,
+}
+
+impl Display for AlternativeDenial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        write!(f, "({} NAND {})", self.left, self.right)
+    }
+}
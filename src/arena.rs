@@ -0,0 +1,343 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{
+    alternative_denial::AlternativeDenial,
+    bi_implication::BiImplication,
+    conjunction::Conjunction,
+    disjunction::Disjunction,
+    errors::{RaaError, Result},
+    exclusive_disjunction::ExclusiveDisjunction,
+    implication::Implication,
+    joint_denial::JointDenial,
+    negation::Negation,
+    proposition::Proposition,
+};
+
+/// The id of a proposition interned in a [`PropositionArena`]
+///
+/// Ids are cheap to copy. Two ids of the same arena are equal if and only if the propositions they
+/// refer to are structurally equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PropId(u32);
+
+impl PropId {
+    /// Returns the position of the proposition in its arena.
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A node of a [`PropositionArena`]
+///
+/// Sub-propositions are referred to by their ids. Variables are referred to by their index into
+/// [`PropositionArena::variables`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropNode {
+    Constant(bool),
+    Atom(usize),
+    Negation(PropId),
+    Implication(PropId, PropId),
+    BiImplication(PropId, PropId),
+    Disjunction(PropId, PropId),
+    Conjunction(PropId, PropId),
+    ExclusiveDisjunction(PropId, PropId),
+    AlternativeDenial(PropId, PropId),
+    JointDenial(PropId, PropId),
+}
+
+/// A store of hash-consed propositions
+///
+/// Each distinct proposition is stored exactly once. Interning a proposition that is already known
+/// returns the id of the existing node. Thus copying a proposition is a copy of its [`PropId`] and
+/// comparing two propositions is a comparison of their ids.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{arena::PropositionArena, proposition::Proposition, negation::Negation};
+///
+/// let mut arena = PropositionArena::new();
+/// let p = arena.intern(&"p".into()).unwrap();
+/// let not_p = arena.intern(&Proposition::Negation(Negation { inner: Box::new("p".into()) }));
+/// assert_eq!(arena.negate(p), not_p.unwrap());
+/// assert_eq!(arena.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PropositionArena {
+    nodes: Vec<PropNode>,
+    sizes: Vec<usize>,
+    ids: HashMap<PropNode, PropId>,
+    variables: Vec<String>,
+    variable_indices: HashMap<String, usize>,
+}
+
+impl PropositionArena {
+    /// Creates a new, empty arena.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of distinct propositions in the arena.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the arena contains no propositions.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the node with the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id doesn't belong to this arena.
+    pub fn node(&self, id: PropId) -> PropNode {
+        self.nodes[id.index()]
+    }
+
+    /// Returns the number of atoms and connectives of the proposition with the given id.
+    pub fn size(&self, id: PropId) -> usize {
+        self.sizes[id.index()]
+    }
+
+    /// Returns the variables of all interned propositions in the order they were encountered.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Returns the index of the given variable, if it is known to the arena.
+    pub fn variable_index(&self, name: &str) -> Option<usize> {
+        self.variable_indices.get(name).copied()
+    }
+
+    /// Inserts a node and returns its id. If the node is already known, its existing id is
+    /// returned.
+    pub fn insert(&mut self, node: PropNode) -> PropId {
+        if let Some(id) = self.ids.get(&node) {
+            return *id;
+        }
+        let id = PropId(self.nodes.len() as u32);
+        let size = match node {
+            PropNode::Constant(_) | PropNode::Atom(_) => 1,
+            PropNode::Negation(inner) => 1 + self.size(inner),
+            PropNode::Implication(left, right)
+            | PropNode::BiImplication(left, right)
+            | PropNode::Disjunction(left, right)
+            | PropNode::Conjunction(left, right)
+            | PropNode::ExclusiveDisjunction(left, right)
+            | PropNode::AlternativeDenial(left, right)
+            | PropNode::JointDenial(left, right) => 1 + self.size(left) + self.size(right),
+        };
+        self.nodes.push(node);
+        self.sizes.push(size);
+        self.ids.insert(node, id);
+        id
+    }
+
+    /// Returns the id of the atomic proposition with the given variable name.
+    pub fn atom(&mut self, name: &str) -> PropId {
+        let index = match self.variable_indices.get(name) {
+            Some(index) => *index,
+            None => {
+                self.variables.push(name.to_owned());
+                self.variable_indices
+                    .insert(name.to_owned(), self.variables.len() - 1);
+                self.variables.len() - 1
+            }
+        };
+        self.insert(PropNode::Atom(index))
+    }
+
+    /// Returns the id of the negation of the given proposition.
+    pub fn negate(&mut self, id: PropId) -> PropId {
+        self.insert(PropNode::Negation(id))
+    }
+
+    /// Interns a proposition with all its sub-propositions and returns its id.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError::VoidExpression`] if the proposition contains void expressions.
+    ///
+    /// [`RaaError::VoidExpression`]: crate::errors::RaaError::VoidExpression
+    pub fn intern(&mut self, proposition: &Proposition) -> Result<PropId> {
+        self.try_intern(proposition).ok_or(RaaError::VoidExpression)
+    }
+
+    // Interns the proposition, None if it contains void expressions. The recursion passes small
+    // options instead of results to keep its frames small for deeply nested propositions.
+    fn try_intern(&mut self, proposition: &Proposition) -> Option<PropId> {
+        let node = match proposition {
+            Proposition::Void => None?,
+            Proposition::Constant(value) => PropNode::Constant(*value),
+            Proposition::Atom(a) => return Some(self.atom(a)),
+            Proposition::Negation(Negation { inner }) => {
+                PropNode::Negation(self.try_intern(inner)?)
+            }
+            Proposition::Implication(Implication { left, right }) => {
+                PropNode::Implication(self.try_intern(left)?, self.try_intern(right)?)
+            }
+            Proposition::BiImplication(BiImplication { left, right }) => {
+                PropNode::BiImplication(self.try_intern(left)?, self.try_intern(right)?)
+            }
+            Proposition::Disjunction(Disjunction { left, right }) => {
+                PropNode::Disjunction(self.try_intern(left)?, self.try_intern(right)?)
+            }
+            Proposition::Conjunction(Conjunction { left, right }) => {
+                PropNode::Conjunction(self.try_intern(left)?, self.try_intern(right)?)
+            }
+            Proposition::ExclusiveDisjunction(ExclusiveDisjunction { left, right }) => {
+                PropNode::ExclusiveDisjunction(self.try_intern(left)?, self.try_intern(right)?)
+            }
+            Proposition::AlternativeDenial(AlternativeDenial { left, right }) => {
+                PropNode::AlternativeDenial(self.try_intern(left)?, self.try_intern(right)?)
+            }
+            Proposition::JointDenial(JointDenial { left, right }) => {
+                PropNode::JointDenial(self.try_intern(left)?, self.try_intern(right)?)
+            }
+        };
+        Some(self.insert(node))
+    }
+
+    /// Converts the interned proposition back into a [`Proposition`] tree.
+    pub fn to_proposition(&self, id: PropId) -> Proposition {
+        let boxed = |id| Box::new(self.to_proposition(id));
+        match self.node(id) {
+            PropNode::Constant(value) => Proposition::Constant(value),
+            PropNode::Atom(v) => Proposition::Atom(self.variables[v].clone()),
+            PropNode::Negation(inner) => Proposition::Negation(Negation {
+                inner: boxed(inner),
+            }),
+            PropNode::Implication(left, right) => Proposition::Implication(Implication {
+                left: boxed(left),
+                right: boxed(right),
+            }),
+            PropNode::BiImplication(left, right) => Proposition::BiImplication(BiImplication {
+                left: boxed(left),
+                right: boxed(right),
+            }),
+            PropNode::Disjunction(left, right) => Proposition::Disjunction(Disjunction {
+                left: boxed(left),
+                right: boxed(right),
+            }),
+            PropNode::Conjunction(left, right) => Proposition::Conjunction(Conjunction {
+                left: boxed(left),
+                right: boxed(right),
+            }),
+            PropNode::ExclusiveDisjunction(left, right) => {
+                Proposition::ExclusiveDisjunction(ExclusiveDisjunction {
+                    left: boxed(left),
+                    right: boxed(right),
+                })
+            }
+            PropNode::AlternativeDenial(left, right) => {
+                Proposition::AlternativeDenial(AlternativeDenial {
+                    left: boxed(left),
+                    right: boxed(right),
+                })
+            }
+            PropNode::JointDenial(left, right) => Proposition::JointDenial(JointDenial {
+                left: boxed(left),
+                right: boxed(right),
+            }),
+        }
+    }
+
+    /// Returns the indices of the variables that occur in the given proposition.
+    pub fn variables_of(&self, id: PropId) -> BTreeSet<usize> {
+        let mut variables = BTreeSet::new();
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            match self.node(id) {
+                PropNode::Constant(_) => (),
+                PropNode::Atom(v) => {
+                    variables.insert(v);
+                }
+                PropNode::Negation(inner) => stack.push(inner),
+                PropNode::Implication(left, right)
+                | PropNode::BiImplication(left, right)
+                | PropNode::Disjunction(left, right)
+                | PropNode::Conjunction(left, right)
+                | PropNode::ExclusiveDisjunction(left, right)
+                | PropNode::AlternativeDenial(left, right)
+                | PropNode::JointDenial(left, right) => {
+                    stack.push(left);
+                    stack.push(right);
+                }
+            }
+        }
+        variables
+    }
+
+    /// Evaluates the proposition with the given id.
+    ///
+    /// The values are indexed by the variable indices of this arena.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a variable of the proposition has no value.
+    pub fn evaluate(&self, id: PropId, values: &[bool]) -> bool {
+        match self.node(id) {
+            PropNode::Constant(value) => value,
+            PropNode::Atom(v) => values[v],
+            PropNode::Negation(inner) => !self.evaluate(inner, values),
+            PropNode::Implication(left, right) => {
+                !self.evaluate(left, values) || self.evaluate(right, values)
+            }
+            PropNode::BiImplication(left, right) => {
+                self.evaluate(left, values) == self.evaluate(right, values)
+            }
+            PropNode::Disjunction(left, right) => {
+                self.evaluate(left, values) || self.evaluate(right, values)
+            }
+            PropNode::Conjunction(left, right) => {
+                self.evaluate(left, values) && self.evaluate(right, values)
+            }
+            PropNode::ExclusiveDisjunction(left, right) => {
+                self.evaluate(left, values) != self.evaluate(right, values)
+            }
+            PropNode::AlternativeDenial(left, right) => {
+                !(self.evaluate(left, values) && self.evaluate(right, values))
+            }
+            PropNode::JointDenial(left, right) => {
+                !(self.evaluate(left, values) || self.evaluate(right, values))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        arena::{PropNode, PropositionArena},
+        conjunction::Conjunction,
+        implication::Implication,
+        proposition::Proposition,
+    };
+
+    #[test]
+    fn test_hash_consing() {
+        // (p -> q) & (p -> q)
+        let implication = Proposition::Implication(Implication {
+            left: Box::new("p".into()),
+            right: Box::new("q".into()),
+        });
+        let proposition = Proposition::Conjunction(Conjunction {
+            left: Box::new(implication.clone()),
+            right: Box::new(implication.clone()),
+        });
+        let mut arena = PropositionArena::new();
+        let id = arena.intern(&proposition).unwrap();
+        // p, q, p -> q and the conjunction
+        assert_eq!(4, arena.len());
+        let PropNode::Conjunction(left, right) = arena.node(id) else {
+            panic!("Conjunction expected");
+        };
+        assert_eq!(left, right);
+        assert_eq!(left, arena.intern(&implication).unwrap());
+        assert_eq!(7, arena.size(id));
+        assert_eq!(proposition, arena.to_proposition(id));
+        assert_eq!(vec!["p", "q"], arena.variables());
+    }
+}
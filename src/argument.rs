@@ -0,0 +1,128 @@
+use std::fmt::{Debug, Display, Error, Formatter};
+
+use crate::{
+    assignment::Assignment,
+    conjunction::Conjunction,
+    implication::Implication,
+    notation::{Notated, Notation},
+    proposition::{Proposition, SyntaxTree},
+    raa_tt_grammar::Associativity,
+    raa_tt_grammar_trait::{RaaTtList, Theorems},
+};
+
+/// Argument struct represents an inference from a list of premises to a conclusion.
+///
+/// Arguments are written with a turnstile, e.g. `p -> q, p |- q`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Argument {
+    pub premises: Vec<Proposition>,
+    pub conclusion: Proposition,
+}
+
+impl Argument {
+    /// Returns the proposition `(P1 & ... & Pn) -> C` which is a tautology if and only if the
+    /// argument is valid.
+    ///
+    /// Without premises the conclusion itself is returned.
+    pub fn to_implication(&self) -> Proposition {
+        let mut premises = self.premises.iter().cloned();
+        match premises.next() {
+            None => self.conclusion.clone(),
+            Some(first) => Proposition::Implication(Implication {
+                left: Box::new(premises.fold(first, |left, right| {
+                    Proposition::Conjunction(Conjunction {
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    })
+                })),
+                right: Box::new(self.conclusion.clone()),
+            }),
+        }
+    }
+
+    /// Returns a wrapper that displays the argument in the given notation.
+    ///
+    /// The [`Display`] implementation of the argument itself uses [`Notation::Ascii`].
+    pub fn with_notation(&self, notation: Notation) -> Notated<'_, Argument> {
+        Notated {
+            value: self,
+            notation,
+        }
+    }
+}
+
+impl Display for Argument {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        write!(f, "{}", self.with_notation(Notation::Ascii))
+    }
+}
+
+/// The outcome of checking an [`Argument`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Validity {
+    /// The conclusion is true under every assignment that makes all premises true
+    Valid,
+
+    /// The contained assignment makes all premises true and the conclusion false
+    Invalid(Assignment),
+}
+
+impl Display for Validity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match self {
+            Validity::Valid => write!(f, "Valid"),
+            Validity::Invalid(_) => write!(f, "Invalid"),
+        }
+    }
+}
+
+/// A single top level item of the input, either a proposition or an argument
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Statement {
+    Proposition(Proposition),
+    Argument(Argument),
+}
+
+// Implication chains are read left associative.
+impl From<&RaaTtList<'_>> for Statement {
+    fn from(value: &RaaTtList<'_>) -> Self {
+        Statement::from_syntax(value, Associativity::Left)
+    }
+}
+
+impl Statement {
+    // Converts a top level item of the syntax tree, folding implication chains with the given
+    // associativity.
+    pub(crate) fn from_syntax(value: &RaaTtList<'_>, implication: Associativity) -> Self {
+        let first = value.biconditional.to_proposition(implication);
+        match &value.raa_tt_opt {
+            None => Statement::Proposition(first),
+            Some(opt) => {
+                let mut premises = vec![first];
+                premises.extend(
+                    opt.argument
+                        .argument_list
+                        .iter()
+                        .map(|a| a.biconditional.to_proposition(implication)),
+                );
+                Statement::Argument(Argument {
+                    premises,
+                    conclusion: opt.argument.biconditional.to_proposition(implication),
+                })
+            }
+        }
+    }
+
+    // Converts the arguments without premises of the syntax tree.
+    pub(crate) fn from_theorems(
+        value: &Theorems<'_>,
+        implication: Associativity,
+    ) -> impl Iterator<Item = Self> {
+        value.theorems_list.iter().map(move |theorem| {
+            Statement::Argument(Argument {
+                premises: Vec::new(),
+                conclusion: theorem.biconditional.to_proposition(implication),
+            })
+        })
+    }
+}
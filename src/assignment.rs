@@ -0,0 +1,93 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display, Error, Formatter},
+};
+
+/// A mapping of propositional variables to truth values.
+///
+/// Assignments are used to report why a proposition is not a tautology (a *counterexample*) or
+/// why it is not a contradiction (a *model*). The variables are kept in alphabetical order, the
+/// same order that is used for the columns of a [`TruthTable`].
+///
+/// [`TruthTable`]: crate::truth_table::TruthTable
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Assignment {
+    values: BTreeMap<String, bool>,
+}
+
+impl Assignment {
+    /// Creates a new, empty assignment.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the truth value of the given variable, if it is assigned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::assignment::Assignment;
+    ///
+    /// let mut assignment = Assignment::new();
+    /// assignment.set("p", true);
+    /// assert_eq!(assignment.get("p"), Some(true));
+    /// assert_eq!(assignment.get("q"), None);
+    /// ```
+    pub fn get(&self, variable: &str) -> Option<bool> {
+        self.values.get(variable).copied()
+    }
+
+    /// Assigns a truth value to a variable, replacing any previous value.
+    pub fn set(&mut self, variable: impl Into<String>, value: bool) {
+        self.values.insert(variable.into(), value);
+    }
+
+    /// Returns an iterator over the variables and their truth values in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, bool)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), *v))
+    }
+
+    /// Returns the number of assigned variables.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if no variable is assigned.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub(crate) fn values(&self) -> &BTreeMap<String, bool> {
+        &self.values
+    }
+}
+
+impl From<BTreeMap<String, bool>> for Assignment {
+    fn from(values: BTreeMap<String, bool>) -> Self {
+        Self { values }
+    }
+}
+
+impl FromIterator<(String, bool)> for Assignment {
+    fn from_iter<T: IntoIterator<Item = (String, bool)>>(iter: T) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Display for Assignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        self.values
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, (var, value))| {
+                let t = if *value { "T" } else { "F" };
+                if i == 0 {
+                    write!(f, "{var} = {t}")
+                } else {
+                    write!(f, ", {var} = {t}")
+                }
+            })
+    }
+}
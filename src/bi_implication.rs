@@ -1,7 +1,7 @@
 use crate::proposition::Proposition;
 use std::fmt::{Debug, Display, Error, Formatter};
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 /// BiImplication struct represents a binary logical biconditional operation.
 pub struct BiImplication {
     pub left: Box<Proposition>,
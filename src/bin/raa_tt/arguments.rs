@@ -1,11 +1,120 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use raa_tt::{notation, strategy::ExpansionStrategy};
+
+/// Output formats for truth trees
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum TreeFormat {
+    /// Graphviz DOT language
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+/// Formats of the input
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub(crate) enum InputFormat {
+    /// Propositions and arguments like `p -> q, p |- q`
+    #[default]
+    Formula,
+    /// A clause set in DIMACS CNF format
+    Dimacs,
+}
+
+/// Formats in which statements are emitted instead of being decided
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum EmitFormat {
+    /// DIMACS CNF of the Tseitin encoding, satisfiable if the proposition is satisfiable or the
+    /// argument is invalid
+    Dimacs,
+}
+
+/// Decision procedures for propositions and arguments
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Engine {
+    /// Analytic tableaux (truth trees)
+    #[default]
+    Tableau,
+    /// Enumeration of all assignments (at most 16 variables)
+    TruthTable,
+    /// DPLL satisfiability solver
+    Dpll,
+    /// CDCL satisfiability solver for formulas with many variables
+    Cdcl,
+}
+
+/// Order in which the prover decomposes the nodes of a truth tree
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Strategy {
+    /// In the order the nodes were added
+    #[default]
+    InOrder,
+    /// Non-branching rules before branching rules
+    AlphaFirst,
+    /// Smallest propositions first
+    SmallestFirst,
+    /// Nodes that leave the fewest open branches first
+    MostConstrainedFirst,
+}
+
+impl From<Strategy> for ExpansionStrategy {
+    fn from(value: Strategy) -> Self {
+        match value {
+            Strategy::InOrder => ExpansionStrategy::InOrder,
+            Strategy::AlphaFirst => ExpansionStrategy::AlphaFirst,
+            Strategy::SmallestFirst => ExpansionStrategy::SmallestFirst,
+            Strategy::MostConstrainedFirst => ExpansionStrategy::MostConstrainedFirst,
+        }
+    }
+}
+
+/// Notations in which propositions are printed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Notation {
+    /// `!`, `&`, `|`, `->`, `<->`, `^`, `NAND` and `NOR`
+    #[default]
+    Ascii,
+    /// `~`, `/\`, `\/`, `=>` and `<=>`
+    Slashes,
+    /// `¬`, `∧`, `∨`, `→`, `↔`, `⊕`, `↑` and `↓`
+    Unicode,
+    /// `not`, `and`, `or`, `xor`, `nand` and `nor`
+    Words,
+}
+
+impl From<Notation> for notation::Notation {
+    fn from(value: Notation) -> Self {
+        match value {
+            Notation::Ascii => notation::Notation::Ascii,
+            Notation::Slashes => notation::Notation::Slashes,
+            Notation::Unicode => notation::Notation::Unicode,
+            Notation::Words => notation::Notation::Words,
+        }
+    }
+}
+
+/// Commands besides deciding the statements of the input
+#[derive(Subcommand)]
+pub(crate) enum Command {
+    /// Check whether two propositions are logically equivalent
+    Equiv(EquivArgs),
+}
+
+#[derive(Args)]
+pub(crate) struct EquivArgs {
+    /// The two propositions, e.g. equiv -s "p -> q" -s "!p | q"
+    #[arg(short = 's', required = true)]
+    pub texts: Vec<String>,
+}
 
 // Prover for sentences of propositional calculus
 #[derive(Parser)]
-#[command(author, version, about)]
+#[command(author, version, about, subcommand_negates_reqs = true)]
 pub(crate) struct CliArgs {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Input file
     #[arg(short, long, group = "input", required = true)]
     pub file: Option<PathBuf>,
@@ -14,11 +123,81 @@ pub(crate) struct CliArgs {
     #[arg(short = 's', group = "input", required = true)]
     pub text: Option<String>,
 
+    /// Format of the input
+    #[arg(long, value_enum, default_value_t)]
+    pub input_format: InputFormat,
+
+    /// Print the statements in the given format instead of deciding them
+    #[arg(long, value_enum, conflicts_with_all = ["truth_table", "explain", "tree", "models", "count_models"])]
+    pub emit: Option<EmitFormat>,
+
     /// Generate truth table
     #[arg(short, long)]
     pub truth_table: bool,
 
-    /// Decrease verbosity
+    /// Print the models of each proposition, one per line
+    #[arg(long)]
+    pub models: bool,
+
+    /// Print at most this many models
+    #[arg(long, requires = "models")]
+    pub max_models: Option<usize>,
+
+    /// Project the models onto these comma separated variables
+    #[arg(long, requires = "models", value_delimiter = ',')]
+    pub project: Option<Vec<String>>,
+
+    /// Print the number of models of each proposition
+    #[arg(long)]
+    pub count_models: bool,
+
+    /// Print the truth tree of each proposition as indented text
     #[arg(short, long)]
+    pub explain: bool,
+
+    /// Write the truth tree of each proposition in the given format
+    #[arg(long, value_enum)]
+    pub tree: Option<TreeFormat>,
+
+    /// Write the truth trees to this file instead of stdout
+    #[arg(long, requires = "tree")]
+    pub tree_output: Option<PathBuf>,
+
+    /// Decision procedure; --explain and --tree need the tableau engine
+    #[arg(long, value_enum, default_value_t, global = true)]
+    pub engine: Engine,
+
+    /// Order in which the prover decomposes the nodes of a truth tree
+    #[arg(long, value_enum, default_value_t, global = true)]
+    pub strategy: Strategy,
+
+    /// Read chains of implications like `a -> b -> c` as `a -> (b -> c)` instead of
+    /// `(a -> b) -> c`
+    #[arg(long, global = true)]
+    pub right_associative: bool,
+
+    /// Notation of the propositions in the results
+    #[arg(long, value_enum, default_value_t, global = true)]
+    pub notation: Notation,
+
+    /// Print the propositions in the results and truth tables with as few parentheses as the
+    /// precedence of the operators allows
+    #[arg(long, global = true)]
+    pub pretty: bool,
+
+    /// Give up on a proposition after this many milliseconds
+    #[arg(long, global = true)]
+    pub timeout: Option<u64>,
+
+    /// Give up on a proposition whose truth trees need more nodes
+    #[arg(long, global = true)]
+    pub max_nodes: Option<usize>,
+
+    /// Give up on a proposition that takes more nodes from the agenda of its truth trees
+    #[arg(long, global = true)]
+    pub max_steps: Option<usize>,
+
+    /// Decrease verbosity
+    #[arg(short, long, global = true)]
     pub quiet: bool,
 }
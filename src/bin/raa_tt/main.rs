@@ -3,16 +3,29 @@ extern crate parol_runtime;
 mod arguments;
 mod report;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use clap::Parser;
 use parol_runtime::log::debug;
-use raa_tt::prover::Prover;
-use raa_tt::raa_tt_grammar::RaaTtGrammar;
+use raa_tt::argument::{Argument, Statement, Validity};
+use raa_tt::assignment::Assignment;
+use raa_tt::bi_implication::BiImplication;
+use raa_tt::cdcl::CdclSolver;
+use raa_tt::cnf::ClauseSet;
+use raa_tt::config::ProverConfig;
+use raa_tt::conjunction::Conjunction;
+use raa_tt::decider::Decider;
+use raa_tt::dpll::DpllSolver;
+use raa_tt::negation::Negation;
+use raa_tt::pretty::PrettyPrinter;
+use raa_tt::prover::{Equivalence, ProveResult, Prover, Witness};
+use raa_tt::raa_tt_grammar::{Associativity, RaaTtGrammar};
 use raa_tt::raa_tt_parser::parse;
+use raa_tt::stats::ProofStats;
+use raa_tt::tableau::Tableau;
 use raa_tt::{proposition::Proposition, table_generator::TableGenerator};
-use std::{fs, time::Instant};
+use std::{fs, time::Duration};
 
-use crate::arguments::CliArgs;
+use crate::arguments::{CliArgs, Command, EmitFormat, Engine, InputFormat, TreeFormat};
 use crate::report::ErrorReporter;
 
 // To generate on command line:
@@ -24,6 +37,27 @@ fn main() -> Result<()> {
     env_logger::init();
     debug!("env logger started");
 
+    if args.engine != Engine::Tableau && (args.explain || args.tree.is_some()) {
+        bail!("Truth trees are only available with the tableau engine");
+    }
+
+    if let Some(Command::Equiv(equiv_args)) = &args.command {
+        if args.file.is_some() || args.text.is_some() {
+            bail!("The propositions to compare are given after equiv");
+        }
+        let [left, right] = &equiv_args.texts[..] else {
+            bail!("Expected exactly two propositions (-s) to compare");
+        };
+        let (Some(left), Some(right)) = (
+            parse_proposition(&args, left)?,
+            parse_proposition(&args, right)?,
+        ) else {
+            return Ok(());
+        };
+        check_equivalence(&args, &left, &right);
+        return Ok(());
+    }
+
     let (input, file_name) = if let Some(file_name) = args.file.as_ref() {
         (
             fs::read_to_string(file_name.clone())
@@ -31,45 +65,427 @@ fn main() -> Result<()> {
             file_name.to_path_buf(),
         )
     } else {
-        (args.text.unwrap().to_owned(), "direct".to_owned().into())
+        (args.text.clone().unwrap(), "direct".to_owned().into())
+    };
+
+    let quiet = args.quiet || args.emit.is_some();
+
+    let statements = match args.input_format {
+        InputFormat::Formula => {
+            let mut raa_tt_grammar = RaaTtGrammar::with_associativity(associativity(&args));
+            if let Err(e) = parse(&input, &file_name, &mut raa_tt_grammar) {
+                return ErrorReporter::report_error_with_content(&e, file_name, &input);
+            }
+            print_warnings(&raa_tt_grammar);
+            raa_tt_grammar.statements()
+        }
+        InputFormat::Dimacs => {
+            let clause_set = ClauseSet::from_dimacs(&input)
+                .with_context(|| format!("Can't read DIMACS input {}", file_name.display()))?;
+            vec![Statement::Proposition(clause_set.to_proposition())]
+        }
     };
 
-    let quiet = args.quiet;
-
-    let mut raa_tt_grammar = RaaTtGrammar::new();
-    let now = Instant::now();
-    match parse(&input, &file_name, &mut raa_tt_grammar) {
-        Ok(_) => {
-            let elapsed_time = now.elapsed();
-            if !quiet {
-                println!("{}", "-".repeat(80));
-                println!("Parsing took {} milliseconds.", elapsed_time.as_millis());
-                println!();
+    // A DIMACS file contains a single problem.
+    if args.emit == Some(EmitFormat::Dimacs) && statements.len() > 1 {
+        bail!(
+            "DIMACS output holds a single statement, but {} were given",
+            statements.len()
+        );
+    }
+
+    let mut trees = Vec::new();
+    for statement in statements {
+        if !quiet {
+            println!("{}", "-".repeat(80));
+        }
+        if let Some(emit_format) = args.emit {
+            emit(&statement, emit_format)?;
+            continue;
+        }
+        let proposition = match statement {
+            Statement::Proposition(proposition) => {
+                if args.engine == Engine::Tableau {
+                    prove_proposition(&args, &proposition, &mut trees);
+                } else {
+                    decide_proposition(&args, &proposition);
+                }
+                proposition
             }
-            for p in &raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list {
-                let proposition: Proposition = (&p.biconditional).into();
-                if !quiet {
-                    println!("{}", "-".repeat(80));
+            Statement::Argument(argument) => {
+                if args.engine == Engine::Tableau {
+                    check_argument(&args, &argument, &mut trees);
+                } else {
+                    decide_argument(&args, &argument);
                 }
+                argument.to_implication()
+            }
+        };
+
+        if args.truth_table {
+            let table_generator = TableGenerator::new();
+            let mut truth_table = table_generator.generate_truth_table(&proposition)?;
+            if args.pretty
+                && let Some(header) = truth_table.header.last_mut()
+            {
+                *header = format_proposition(&args, &proposition);
+            }
+            println!("{}", truth_table);
+        }
+
+        if args.models {
+            print_models(&args, &proposition);
+        }
+
+        if args.count_models {
+            match proposition.count_models() {
+                Ok(count) => println!("Number of models: {count}"),
+                Err(e) => println!("Error occurred: {e}"),
+            }
+        }
+    }
+    if let Some(tree_file) = &args.tree_output {
+        fs::write(tree_file, trees.join("\n"))
+            .with_context(|| format!("Can't write file {}", tree_file.display()))?;
+    }
+    Ok(())
+}
 
-                let solver = Prover::new();
-                let solve_result = solver.prove(&proposition);
-                match solve_result {
-                    Ok(r) => {
-                        println!();
-                        println!("{proposition} is {r}");
-                    }
-                    Err(e) => println!("Error occurred: {e}"),
+// Prints the models of a proposition found by the selected engine, one per line.
+fn print_models(args: &CliArgs, proposition: &Proposition) {
+    let table_generator = TableGenerator::new();
+    let prover = create_prover(args);
+    let dpll = DpllSolver::new();
+    let cdcl = CdclSolver::new();
+    let mut models = match args.engine {
+        Engine::Tableau => prover.models(proposition),
+        Engine::TruthTable => table_generator.models(proposition),
+        Engine::Dpll => dpll.models(proposition),
+        Engine::Cdcl => cdcl.models(proposition),
+    };
+    if let Some(projection) = &args.project {
+        models = models.project(projection.iter().cloned());
+    }
+    if let Some(max_models) = args.max_models {
+        models = models.limit(max_models);
+    }
+    if !args.quiet {
+        println!("Models:");
+    }
+    let mut count = 0;
+    for model in models {
+        match model {
+            Ok(model) => {
+                count += 1;
+                println!("{model}");
+            }
+            Err(e) => println!("Error occurred: {e}"),
+        }
+    }
+    if count == 0 && !args.quiet {
+        println!("No models");
+    }
+}
+
+fn associativity(args: &CliArgs) -> Associativity {
+    if args.right_associative {
+        Associativity::Right
+    } else {
+        Associativity::Left
+    }
+}
+
+// Warnings are printed to stderr regardless of --quiet.
+fn print_warnings(raa_tt_grammar: &RaaTtGrammar) {
+    for warning in &raa_tt_grammar.warnings {
+        eprintln!("Warning: {warning}");
+    }
+}
+
+// Parses a single proposition given on the command line.
+// Syntax errors are reported and yield None.
+fn parse_proposition(args: &CliArgs, input: &str) -> Result<Option<Proposition>> {
+    let mut raa_tt_grammar = RaaTtGrammar::with_associativity(associativity(args));
+    if let Err(e) = parse(input, "direct", &mut raa_tt_grammar) {
+        ErrorReporter::report_error_with_content(&e, "direct", input)?;
+        return Ok(None);
+    }
+    print_warnings(&raa_tt_grammar);
+    match raa_tt_grammar.statements().as_slice() {
+        [Statement::Proposition(proposition)] => Ok(Some(proposition.clone())),
+        [Statement::Argument(argument)] => bail!("Expected a proposition, found {argument}"),
+        _ => bail!("Expected a single proposition in {input}"),
+    }
+}
+
+// Checks whether two propositions are equivalent with the selected engine.
+// The other engines search for a model of the negated bi-implication.
+fn check_equivalence(args: &CliArgs, left: &Proposition, right: &Proposition) {
+    let equivalence = if args.engine == Engine::Tableau {
+        create_prover(args).equivalent(left, right)
+    } else {
+        create_decider(args)
+            .find_model(&Proposition::Negation(Negation {
+                inner: Box::new(Proposition::BiImplication(BiImplication {
+                    left: Box::new(left.clone()),
+                    right: Box::new(right.clone()),
+                })),
+            }))
+            .map(|model| model.map_or(Equivalence::Equivalent, Equivalence::Different))
+    };
+    let (left, right) = (format_proposition(args, left), format_proposition(args, right));
+    match &equivalence {
+        Ok(Equivalence::Different(assignment)) => println!(
+            "{left} and {right} are {}, distinguishing assignment: {assignment}",
+            equivalence.as_ref().unwrap()
+        ),
+        Ok(Equivalence::Equivalent) => println!("{left} and {right} are Equivalent"),
+        Err(e) => println!("Error occurred: {e}"),
+    }
+}
+
+// Prints a statement in the given format.
+// An argument is emitted as the conjunction of its premises and the negated conclusion, which is
+// satisfiable if and only if the argument is invalid.
+fn emit(statement: &Statement, emit_format: EmitFormat) -> Result<()> {
+    let (heading, proposition) = match statement {
+        Statement::Proposition(proposition) => (proposition.to_string(), proposition.clone()),
+        Statement::Argument(argument) => (
+            argument.to_string(),
+            argument
+                .premises
+                .iter()
+                .cloned()
+                .chain([Proposition::Negation(Negation {
+                    inner: Box::new(argument.conclusion.clone()),
+                })])
+                .reduce(|left, right| {
+                    Proposition::Conjunction(Conjunction {
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    })
+                })
+                .unwrap(),
+        ),
+    };
+    match emit_format {
+        EmitFormat::Dimacs => {
+            let clause_set = proposition.to_tseitin_cnf()?;
+            print!("c {heading}\n{}", clause_set.to_dimacs());
+        }
+    }
+    Ok(())
+}
+
+// Creates the prover configured by the arguments. The timeout applies to each of its calls.
+fn create_prover(args: &CliArgs) -> Prover {
+    let config = ProverConfig {
+        strategy: args.strategy.into(),
+        max_nodes: args.max_nodes,
+        max_steps: args.max_steps,
+        ..Default::default()
+    };
+    Prover::with_config(match args.timeout {
+        Some(timeout) => config.with_timeout(Duration::from_millis(timeout)),
+        None => config,
+    })
+}
+
+fn prove_proposition(args: &CliArgs, proposition: &Proposition, trees: &mut Vec<String>) {
+    let solver = create_prover(args);
+    if args.explain || args.tree.is_some() {
+        match solver.prove_with_tableaux(proposition) {
+            Ok(proof) => {
+                print_result(args, proposition, proof.result, proof.counterexample());
+                print_stats(args, &proof.stats);
+                let heading = format!(
+                    "{} is {}",
+                    format_proposition(args, proposition),
+                    proof.result
+                );
+                output_tree(args, &heading, proof.tableau(), trees);
+            }
+            Err(e) => println!("Error occurred: {e}"),
+        }
+    } else {
+        match solver.prove_with_witness(proposition) {
+            Ok(Witness {
+                result,
+                counterexample,
+                stats,
+                ..
+            }) => {
+                print_result(args, proposition, result, counterexample);
+                print_stats(args, &stats);
+            }
+            Err(e) => println!("Error occurred: {e}"),
+        }
+    }
+}
+
+fn check_argument(args: &CliArgs, argument: &Argument, trees: &mut Vec<String>) {
+    let solver = create_prover(args);
+    let result = if args.explain || args.tree.is_some() {
+        solver
+            .entails_with_tableau(&argument.premises, &argument.conclusion)
+            .map(|(validity, tableau, stats)| (validity, Some(tableau), stats))
+    } else {
+        solver
+            .entails_with_stats(&argument.premises, &argument.conclusion)
+            .map(|(validity, stats)| (validity, None, stats))
+    };
+    match result {
+        Ok((validity, tableau, stats)) => {
+            let argument = format_argument(args, argument);
+            println!();
+            match &validity {
+                Validity::Invalid(counterexample) => {
+                    println!("{argument} is {validity}, counterexample: {counterexample}")
                 }
+                Validity::Valid => println!("{argument} is {validity}"),
+            }
+            print_stats(args, &stats);
+            if let Some(tableau) = tableau {
+                let heading = format!("{argument} is {validity}");
+                output_tree(args, &heading, &tableau, trees);
+            }
+        }
+        Err(e) => println!("Error occurred: {e}"),
+    }
+}
+
+// Creates the decider of the selected engine.
+fn create_decider(args: &CliArgs) -> Box<dyn Decider> {
+    match args.engine {
+        Engine::Tableau => Box::new(create_prover(args)),
+        Engine::TruthTable => Box::new(TableGenerator::new()),
+        Engine::Dpll => Box::new(DpllSolver::new()),
+        Engine::Cdcl => Box::new(CdclSolver::new()),
+    }
+}
+
+// Decides a proposition with the selected engine.
+// The counterexample is a model of the negated proposition.
+fn decide(
+    args: &CliArgs,
+    proposition: &Proposition,
+) -> raa_tt::errors::Result<(ProveResult, Option<Assignment>)> {
+    let decider = create_decider(args);
+    let result = decider.classify(proposition)?;
+    let counterexample = if result == ProveResult::Proven {
+        None
+    } else {
+        decider.find_model(&Proposition::Negation(Negation {
+            inner: Box::new(proposition.clone()),
+        }))?
+    };
+    Ok((result, counterexample))
+}
 
-                if args.truth_table {
-                    let table_generator = TableGenerator::new();
-                    let truth_table = table_generator.generate_truth_table(&proposition)?;
-                    println!("{}", truth_table);
+fn decide_proposition(args: &CliArgs, proposition: &Proposition) {
+    match decide(args, proposition) {
+        Ok((result, counterexample)) => print_result(args, proposition, result, counterexample),
+        Err(e) => println!("Error occurred: {e}"),
+    }
+}
+
+// An argument is valid if the implication from its premises to its conclusion is a tautology.
+fn decide_argument(args: &CliArgs, argument: &Argument) {
+    match decide(args, &argument.to_implication()) {
+        Ok((_, counterexample)) => {
+            let argument = format_argument(args, argument);
+            println!();
+            match counterexample {
+                Some(counterexample) => {
+                    let validity = Validity::Invalid(counterexample.clone());
+                    println!("{argument} is {validity}, counterexample: {counterexample}")
                 }
+                None => println!("{argument} is {}", Validity::Valid),
             }
-            Ok(())
         }
-        Err(e) => ErrorReporter::report_error_with_content(&e, file_name, &input),
+        Err(e) => println!("Error occurred: {e}"),
+    }
+}
+
+fn print_result(
+    args: &CliArgs,
+    proposition: &Proposition,
+    result: ProveResult,
+    counterexample: Option<Assignment>,
+) {
+    let proposition = format_proposition(args, proposition);
+    println!();
+    match (result, counterexample) {
+        (ProveResult::Contingent, Some(counterexample)) => {
+            println!("{proposition} is {result}, counterexample: {counterexample}")
+        }
+        _ => println!("{proposition} is {result}"),
+    }
+}
+
+// Writes a proposition in the selected notation, with minimal parentheses if --pretty is given.
+fn format_proposition(args: &CliArgs, proposition: &Proposition) -> String {
+    let notation = args.notation.into();
+    if args.pretty {
+        PrettyPrinter {
+            notation,
+            ..Default::default()
+        }
+        .print(proposition)
+    } else {
+        proposition.with_notation(notation).to_string()
+    }
+}
+
+fn format_argument(args: &CliArgs, argument: &Argument) -> String {
+    let notation = args.notation.into();
+    if !args.pretty {
+        return argument.with_notation(notation).to_string();
+    }
+    let mut premises = argument
+        .premises
+        .iter()
+        .map(|premise| format_proposition(args, premise))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if !premises.is_empty() {
+        premises.push(' ');
+    }
+    format!(
+        "{premises}{} {}",
+        notation.turnstile(),
+        format_proposition(args, &argument.conclusion)
+    )
+}
+
+fn print_stats(args: &CliArgs, stats: &ProofStats) {
+    if !args.quiet {
+        print!("{stats}");
+    }
+}
+
+// Prints the truth tree as text and renders it in the requested export format.
+// Exported trees are printed directly or collected for the output file.
+fn output_tree(args: &CliArgs, heading: &str, tableau: &Tableau, trees: &mut Vec<String>) {
+    if args.explain {
+        println!();
+        print!("{tableau}");
+    }
+    if let Some(tree_format) = args.tree {
+        let tree = render_tree(heading, tableau, tree_format);
+        if args.tree_output.is_some() {
+            trees.push(tree);
+        } else {
+            println!("{tree}");
+        }
+    }
+}
+
+// Renders a truth tree in the given format.
+// A comment line states the proposition and the result.
+fn render_tree(heading: &str, tableau: &Tableau, tree_format: TreeFormat) -> String {
+    match tree_format {
+        TreeFormat::Dot => format!("// {heading}\n{}", tableau.to_dot()),
+        TreeFormat::Mermaid => format!("%% {heading}\n{}", tableau.to_mermaid()),
     }
 }
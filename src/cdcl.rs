@@ -0,0 +1,526 @@
+use std::ops::Not;
+
+use crate::{
+    assignment::Assignment,
+    cnf::{ClauseSet, Literal},
+    decider::Decider,
+    errors::Result,
+    models::Models,
+    proposition::Proposition,
+};
+
+// The number of conflicts of the first restart interval, scaled by the Luby sequence
+const RESTART_INTERVAL: u64 = 100;
+
+// The factor by which the activity increment grows after each conflict
+const ACTIVITY_GROWTH: f64 = 1.0 / 0.95;
+
+// Activities are rescaled when they exceed this limit
+const ACTIVITY_LIMIT: f64 = 1e100;
+
+/// A conflict-driven clause learning (CDCL) satisfiability solver
+///
+/// The proposition is converted into a set of clauses with the Tseitin transformation. Like the
+/// [`DpllSolver`] the solver assigns variables and propagates unit clauses, but it
+/// - watches two literals per clause, so only few clauses are visited after an assignment,
+/// - analyses each conflict and learns a clause that prevents it from happening again,
+/// - jumps back to the decision that caused the conflict instead of the most recent one,
+/// - prefers variables that took part in recent conflicts and
+/// - restarts the search from time to time while keeping the learned clauses.
+///
+/// This makes it the engine of choice for propositions with hundreds of variables.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{
+///     cdcl::CdclSolver,
+///     decider::Decider,
+///     prover::ProveResult,
+///     proposition::Proposition,
+///     conjunction::Conjunction,
+///     negation::Negation,
+/// };
+///
+/// // P ∧ ¬Q
+/// let proposition = Proposition::Conjunction(Conjunction {
+///     left: Box::new("P".into()),
+///     right: Box::new(Proposition::Negation(Negation {
+///         inner: Box::new("Q".into()),
+///     })),
+/// });
+///
+/// let solver = CdclSolver::new();
+/// assert_eq!(solver.classify(&proposition).unwrap(), ProveResult::Contingent);
+/// let model = solver.find_model(&proposition).unwrap().unwrap();
+/// assert_eq!(model.get("P"), Some(true));
+/// assert_eq!(model.get("Q"), Some(false));
+/// ```
+///
+/// [`DpllSolver`]: crate::dpll::DpllSolver
+#[derive(Debug, Default)]
+pub struct CdclSolver {}
+
+impl CdclSolver {
+    /// Creates a new instance of the CdclSolver.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns the values of all variables of a satisfying assignment, if there is one.
+    pub(crate) fn solve(&self, clause_set: &ClauseSet) -> Option<Vec<bool>> {
+        Search::new(clause_set)?.run()
+    }
+
+    /// Returns a lazy enumeration of the models of a proposition.
+    ///
+    /// Like [`Models::new`] each model is blocked by a clause, but a single search is continued
+    /// after each model, so the clauses learned for the previous models are kept. See [`Models`]
+    /// for projections and limits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{cdcl::CdclSolver, proposition::Proposition, disjunction::Disjunction};
+    ///
+    /// // P ∨ Q
+    /// let proposition = Proposition::Disjunction(Disjunction {
+    ///     left: Box::new("P".into()),
+    ///     right: Box::new("Q".into()),
+    /// });
+    ///
+    /// assert_eq!(CdclSolver::new().models(&proposition).count(), 3);
+    /// ```
+    pub fn models(&self, proposition: &Proposition) -> Models<'_> {
+        Models::incremental(proposition)
+    }
+}
+
+impl Decider for CdclSolver {
+    fn find_model(&self, proposition: &Proposition) -> Result<Option<Assignment>> {
+        let clause_set = ClauseSet::tseitin(proposition)?;
+        Ok(self
+            .solve(&clause_set)
+            .map(|values| clause_set.assignment(&values)))
+    }
+
+    fn find_model_with_clauses(
+        &self,
+        _proposition: &Proposition,
+        clause_set: &ClauseSet,
+    ) -> Result<Option<Assignment>> {
+        Ok(self
+            .solve(clause_set)
+            .map(|values| clause_set.assignment(&values)))
+    }
+}
+
+// A literal encoded as 2 * variable + 1 for positive and 2 * variable for negative literals, so
+// literals can index the watch lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Lit(usize);
+
+impl Lit {
+    fn variable(self) -> usize {
+        self.0 / 2
+    }
+
+    fn is_positive(self) -> bool {
+        self.0 % 2 == 1
+    }
+}
+
+impl From<Literal> for Lit {
+    fn from(literal: Literal) -> Self {
+        Lit(2 * literal.variable + literal.positive as usize)
+    }
+}
+
+impl Not for Lit {
+    type Output = Lit;
+
+    fn not(self) -> Self::Output {
+        Lit(self.0 ^ 1)
+    }
+}
+
+// The state of the search.
+// The first two literals of each clause are watched. A clause is in the watch list of the negations
+// of its watched literals, hence it is visited when one of them becomes false.
+// The trail contains the assigned literals in assignment order, `levels_start` the trail position
+// of each decision.
+pub(crate) struct Search {
+    clauses: Vec<Vec<Lit>>,
+    watches: Vec<Vec<usize>>,
+    values: Vec<Option<bool>>,
+    levels: Vec<usize>,
+    reasons: Vec<Option<usize>>,
+    trail: Vec<Lit>,
+    levels_start: Vec<usize>,
+    propagated: usize,
+    activities: Vec<f64>,
+    activity_increment: f64,
+    phases: Vec<bool>,
+}
+
+impl Search {
+    // Sets up the search. Returns `None` if the clauses are trivially unsatisfiable.
+    pub(crate) fn new(clause_set: &ClauseSet) -> Option<Self> {
+        let variable_count = clause_set.variable_count;
+        let mut search = Self {
+            clauses: Vec::new(),
+            watches: vec![Vec::new(); 2 * variable_count],
+            values: vec![None; variable_count],
+            levels: vec![0; variable_count],
+            reasons: vec![None; variable_count],
+            trail: Vec::new(),
+            levels_start: Vec::new(),
+            propagated: 0,
+            activities: vec![0.0; variable_count],
+            activity_increment: 1.0,
+            phases: vec![false; variable_count],
+        };
+        for clause in &clause_set.clauses {
+            let mut lits: Vec<Lit> = clause.iter().map(|l| Lit::from(*l)).collect();
+            lits.sort_by_key(|l| l.0);
+            lits.dedup();
+            if lits.windows(2).any(|w| w[0] == !w[1]) {
+                // Tautological clause
+                continue;
+            }
+            match lits.len() {
+                0 => return None,
+                1 => match search.value(lits[0]) {
+                    Some(false) => return None,
+                    Some(true) => (),
+                    None => search.assign(lits[0], None),
+                },
+                _ => {
+                    search.add_clause(lits);
+                }
+            }
+        }
+        Some(search)
+    }
+
+    // Runs the search until all variables are assigned or a conflict occurs on level 0.
+    pub(crate) fn run(&mut self) -> Option<Vec<bool>> {
+        let mut conflicts = 0;
+        let mut restart = 1;
+        let mut restart_limit = RESTART_INTERVAL * luby(restart);
+        loop {
+            if let Some(conflict) = self.propagate() {
+                if self.levels_start.is_empty() {
+                    return None;
+                }
+                conflicts += 1;
+                let (learned, level) = self.analyze(conflict);
+                self.backtrack(level);
+                if learned.len() == 1 {
+                    self.assign(learned[0], None);
+                } else {
+                    let asserting = learned[0];
+                    let clause = self.add_clause(learned);
+                    self.assign(asserting, Some(clause));
+                }
+                self.activity_increment *= ACTIVITY_GROWTH;
+            } else if conflicts >= restart_limit {
+                conflicts = 0;
+                restart += 1;
+                restart_limit = RESTART_INTERVAL * luby(restart);
+                self.backtrack(0);
+            } else {
+                let Some(variable) = self.choose_variable() else {
+                    return Some(self.values.iter().map(|v| v.unwrap_or(false)).collect());
+                };
+                self.levels_start.push(self.trail.len());
+                let lit = Lit(2 * variable + self.phases[variable] as usize);
+                self.assign(lit, None);
+            }
+        }
+    }
+
+    // Adds a clause after a successful search, e.g. to block the model found. The learned clauses
+    // are kept, so the next search continues from what has been learned so far. Returns false if
+    // the clauses have become unsatisfiable.
+    pub(crate) fn add(&mut self, clause: &[Literal]) -> bool {
+        self.backtrack(0);
+        let mut lits: Vec<Lit> = clause
+            .iter()
+            .map(|l| Lit::from(*l))
+            .filter(|l| self.value(*l) != Some(false))
+            .collect();
+        lits.sort_by_key(|l| l.0);
+        lits.dedup();
+        if lits.iter().any(|l| self.value(*l) == Some(true))
+            || lits.windows(2).any(|w| w[0] == !w[1])
+        {
+            return true;
+        }
+        match lits.len() {
+            0 => false,
+            1 => {
+                self.assign(lits[0], None);
+                true
+            }
+            _ => {
+                self.add_clause(lits);
+                true
+            }
+        }
+    }
+
+    fn value(&self, lit: Lit) -> Option<bool> {
+        self.values[lit.variable()].map(|v| v == lit.is_positive())
+    }
+
+    fn assign(&mut self, lit: Lit, reason: Option<usize>) {
+        let variable = lit.variable();
+        self.values[variable] = Some(lit.is_positive());
+        self.levels[variable] = self.levels_start.len();
+        self.reasons[variable] = reason;
+        self.trail.push(lit);
+    }
+
+    // Adds a clause with at least two literals and watches its first two literals.
+    fn add_clause(&mut self, lits: Vec<Lit>) -> usize {
+        let index = self.clauses.len();
+        self.watches[(!lits[0]).0].push(index);
+        self.watches[(!lits[1]).0].push(index);
+        self.clauses.push(lits);
+        index
+    }
+
+    // Propagates the assignments of the trail. Returns the index of a falsified clause.
+    fn propagate(&mut self) -> Option<usize> {
+        while self.propagated < self.trail.len() {
+            let lit = self.trail[self.propagated];
+            self.propagated += 1;
+            let false_lit = !lit;
+            let watching = std::mem::take(&mut self.watches[lit.0]);
+            let mut kept = Vec::with_capacity(watching.len());
+            let mut conflict = None;
+            for (i, &clause_index) in watching.iter().enumerate() {
+                if conflict.is_some() {
+                    kept.extend_from_slice(&watching[i..]);
+                    break;
+                }
+                let clause = &mut self.clauses[clause_index];
+                // The false literal is moved to the second position.
+                if clause[0] == false_lit {
+                    clause.swap(0, 1);
+                }
+                let first = clause[0];
+                if self.values[first.variable()].map(|v| v == first.is_positive()) == Some(true) {
+                    kept.push(clause_index);
+                    continue;
+                }
+                // Look for a new literal to watch.
+                let replacement = (2..clause.len()).find(|k| {
+                    let l = clause[*k];
+                    self.values[l.variable()].map(|v| v == l.is_positive()) != Some(false)
+                });
+                if let Some(k) = replacement {
+                    clause.swap(1, k);
+                    let watched = clause[1];
+                    self.watches[(!watched).0].push(clause_index);
+                    continue;
+                }
+                kept.push(clause_index);
+                match self.value(first) {
+                    Some(false) => conflict = Some(clause_index),
+                    _ => self.assign(first, Some(clause_index)),
+                }
+            }
+            self.watches[lit.0].extend(kept);
+            if conflict.is_some() {
+                return conflict;
+            }
+        }
+        None
+    }
+
+    // Derives the first unique implication point clause from a conflict.
+    // Returns the learned clause with the asserting literal first and the level to jump back to.
+    fn analyze(&mut self, conflict: usize) -> (Vec<Lit>, usize) {
+        let level = self.levels_start.len();
+        let mut seen = vec![false; self.values.len()];
+        let mut learned = vec![Lit(0)];
+        let mut open = 0;
+        let mut clause = conflict;
+        let mut index = self.trail.len();
+        let mut skip = 0;
+        let asserting = loop {
+            for k in skip..self.clauses[clause].len() {
+                let lit = self.clauses[clause][k];
+                let variable = lit.variable();
+                if seen[variable] || self.levels[variable] == 0 {
+                    continue;
+                }
+                seen[variable] = true;
+                self.bump(variable);
+                if self.levels[variable] == level {
+                    open += 1;
+                } else {
+                    learned.push(lit);
+                }
+            }
+            // Continue with the most recent literal of the current level that has been seen.
+            let lit = loop {
+                index -= 1;
+                if seen[self.trail[index].variable()] {
+                    break self.trail[index];
+                }
+            };
+            seen[lit.variable()] = false;
+            open -= 1;
+            if open == 0 {
+                break lit;
+            }
+            clause = self.reasons[lit.variable()].expect("Implied literal expected");
+            // The first literal of a reason clause is the implied literal itself.
+            skip = 1;
+        };
+        learned[0] = !asserting;
+        // The literal of the highest remaining level is watched, too.
+        let backtrack_level =
+            match (1..learned.len()).max_by_key(|k| self.levels[learned[*k].variable()]) {
+                Some(k) => {
+                    learned.swap(1, k);
+                    self.levels[learned[1].variable()]
+                }
+                None => 0,
+            };
+        (learned, backtrack_level)
+    }
+
+    // Undoes all assignments above the given level.
+    fn backtrack(&mut self, level: usize) {
+        if self.levels_start.len() <= level {
+            return;
+        }
+        let start = self.levels_start[level];
+        for lit in self.trail.drain(start..) {
+            let variable = lit.variable();
+            self.phases[variable] = lit.is_positive();
+            self.values[variable] = None;
+            self.reasons[variable] = None;
+        }
+        self.levels_start.truncate(level);
+        self.propagated = start;
+    }
+
+    fn bump(&mut self, variable: usize) {
+        self.activities[variable] += self.activity_increment;
+        if self.activities[variable] > ACTIVITY_LIMIT {
+            self.activities
+                .iter_mut()
+                .for_each(|a| *a /= ACTIVITY_LIMIT);
+            self.activity_increment /= ACTIVITY_LIMIT;
+        }
+    }
+
+    // Returns the unassigned variable with the highest activity.
+    fn choose_variable(&self) -> Option<usize> {
+        self.values
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_none())
+            .max_by(|(a, _), (b, _)| self.activities[*a].total_cmp(&self.activities[*b]))
+            .map(|(variable, _)| variable)
+    }
+}
+
+// The Luby sequence 1, 1, 2, 1, 1, 2, 4, 1, 1, 2, ... starting at index 1.
+fn luby(index: u64) -> u64 {
+    let mut index = index;
+    loop {
+        let k = 64 - index.leading_zeros() as u64;
+        if index == (1 << k) - 1 {
+            return 1 << (k - 1);
+        }
+        index -= (1 << (k - 1)) - 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cdcl::{CdclSolver, luby},
+        cnf::{ClauseSet, Literal},
+        dpll::DpllSolver,
+    };
+
+    #[test]
+    fn test_luby() {
+        let sequence = (1..=15).map(luby).collect::<Vec<_>>();
+        assert_eq!(vec![1, 1, 2, 1, 1, 2, 4, 1, 1, 2, 1, 1, 2, 4, 8], sequence);
+    }
+
+    #[test]
+    fn test_pigeonhole() {
+        // n + 1 pigeons in n holes, variable n * pigeon + hole
+        let pigeonhole = |n: usize| {
+            let mut clauses = (0..=n)
+                .map(|p| (0..n).map(|h| Literal::new(n * p + h, true)).collect())
+                .collect::<Vec<_>>();
+            for h in 0..n {
+                for p in 0..=n {
+                    for q in p + 1..=n {
+                        clauses.push(vec![
+                            Literal::new(n * p + h, false),
+                            Literal::new(n * q + h, false),
+                        ]);
+                    }
+                }
+            }
+            ClauseSet {
+                variables: Vec::new(),
+                variable_count: n * (n + 1),
+                clauses,
+            }
+        };
+        for n in 1..=6 {
+            assert!(CdclSolver::new().solve(&pigeonhole(n)).is_none(), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_random_3_sat() {
+        // Random clause sets around the satisfiability threshold, checked against DPLL
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut random = |bound: usize| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % bound as u64) as usize
+        };
+        for _ in 0..200 {
+            let clauses = (0..55)
+                .map(|_| {
+                    (0..3)
+                        .map(|_| Literal::new(random(12), random(2) == 0))
+                        .collect()
+                })
+                .collect::<Vec<Vec<Literal>>>();
+            let clause_set = ClauseSet {
+                variables: Vec::new(),
+                variable_count: 12,
+                clauses,
+            };
+            let result = CdclSolver::new().solve(&clause_set);
+            assert_eq!(
+                DpllSolver::new().solve(&clause_set).is_some(),
+                result.is_some()
+            );
+            if let Some(values) = result {
+                assert!(
+                    clause_set
+                        .clauses
+                        .iter()
+                        .all(|clause| { clause.iter().any(|l| values[l.variable] == l.positive) })
+                );
+            }
+        }
+    }
+}
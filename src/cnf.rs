@@ -0,0 +1,470 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Error, Formatter},
+    ops::Not,
+};
+
+use crate::{
+    arena::{PropId, PropNode, PropositionArena},
+    assignment::Assignment,
+    conjunction::Conjunction,
+    disjunction::Disjunction,
+    errors::Result,
+    negation::Negation,
+    proposition::Proposition,
+};
+
+/// A variable or its negation
+///
+/// Variables are referred to by their index into the variables of a [`ClauseSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Literal {
+    /// The index of the variable
+    pub variable: usize,
+
+    /// False if the literal is the negation of the variable
+    pub positive: bool,
+}
+
+impl Literal {
+    /// Creates a new literal.
+    pub fn new(variable: usize, positive: bool) -> Self {
+        Self { variable, positive }
+    }
+
+    // Returns the value of the literal if its variable is assigned.
+    pub(crate) fn value(self, values: &[Option<bool>]) -> Option<bool> {
+        values[self.variable].map(|v| v == self.positive)
+    }
+}
+
+impl Not for Literal {
+    type Output = Literal;
+
+    fn not(self) -> Self::Output {
+        Self {
+            positive: !self.positive,
+            ..self
+        }
+    }
+}
+
+/// A proposition in conjunctive normal form
+///
+/// The clause set is a conjunction of clauses, each of them a disjunction of [`Literal`]s. The
+/// first variables are the variables of the converted proposition in the order of their first
+/// occurrence. The remaining ones are auxiliary variables introduced by the Tseitin encoding.
+///
+/// Clause sets are created by [`Proposition::to_cnf`] and [`Proposition::to_tseitin_cnf`]. They
+/// are displayed in the syntax of the parser, auxiliary variables are named `t1`, `t2` and so on,
+/// with underscores appended to `t` if these names are already taken. The empty clause set is
+/// displayed as `⊤`, the empty clause as `⊥`.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{proposition::Proposition, disjunction::Disjunction, conjunction::Conjunction};
+///
+/// // p | (q & r)
+/// let proposition = Proposition::Disjunction(Disjunction {
+///     left: Box::new("p".into()),
+///     right: Box::new(Proposition::Conjunction(Conjunction {
+///         left: Box::new("q".into()),
+///         right: Box::new("r".into()),
+///     })),
+/// });
+///
+/// let cnf = proposition.to_cnf().unwrap();
+/// assert_eq!(cnf.to_string(), "(p | q) & (p | r)");
+///
+/// let tseitin = proposition.to_tseitin_cnf().unwrap();
+/// assert_eq!(tseitin.variable_count, 5);
+/// assert_eq!(tseitin.variable_name(3), "t1");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClauseSet {
+    /// The names of the variables of the converted proposition
+    pub variables: Vec<String>,
+
+    /// The number of variables including the auxiliary variables
+    pub variable_count: usize,
+
+    /// The clauses
+    pub clauses: Vec<Vec<Literal>>,
+}
+
+impl ClauseSet {
+    // Converts a proposition into an equivalent clause set by pushing negations inwards and
+    // distributing disjunctions over conjunctions. The size of the result can grow exponentially
+    // with the size of the proposition. Tautological and duplicate clauses are removed, an empty
+    // clause makes all other clauses superfluous.
+    pub(crate) fn distribute(proposition: &Proposition) -> Result<Self> {
+        let mut arena = PropositionArena::new();
+        let root = arena.intern(proposition)?;
+        let mut clauses = Vec::new();
+        let mut known = HashSet::new();
+        for mut clause in distribute(&arena, root, true) {
+            // Clauses with the same literals in another order are duplicates, too.
+            clause.sort();
+            clause.dedup();
+            if clause.is_empty() {
+                clauses = vec![clause];
+                break;
+            }
+            if known.insert(clause.clone()) {
+                clauses.push(clause);
+            }
+        }
+        Ok(ClauseSet {
+            variables: arena.variables().to_vec(),
+            variable_count: arena.variables().len(),
+            clauses,
+        })
+    }
+
+    // Encodes a proposition with the Tseitin transformation.
+    // Each connective gets an auxiliary variable that is equivalent to its sub-formula, so the
+    // clause set grows linearly with the proposition and is satisfiable if and only if the
+    // proposition is. Negations are folded into the literals. Constants are encoded by an auxiliary
+    // variable that is fixed by a unit clause.
+    pub(crate) fn tseitin(proposition: &Proposition) -> Result<Self> {
+        let mut arena = PropositionArena::new();
+        let root = arena.intern(proposition)?;
+        let mut encoder = TseitinEncoder {
+            arena: &arena,
+            literals: HashMap::new(),
+            clause_set: ClauseSet {
+                variables: arena.variables().to_vec(),
+                variable_count: arena.variables().len(),
+                clauses: Vec::new(),
+            },
+        };
+        let root = encoder.encode(root);
+        encoder.clause_set.clauses.push(vec![root]);
+        Ok(encoder.clause_set)
+    }
+
+    /// Returns true if the variable with the given index is an auxiliary variable.
+    pub fn is_auxiliary(&self, variable: usize) -> bool {
+        variable >= self.variables.len()
+    }
+
+    /// Returns the name of the variable with the given index.
+    pub fn variable_name(&self, variable: usize) -> String {
+        self.name(variable, &self.auxiliary_prefix())
+    }
+
+    /// Returns true if every clause contains a literal that is TRUE under the given values.
+    ///
+    /// The values are indexed by the variable indices of the clause set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a variable of a clause has no value.
+    pub fn evaluate(&self, values: &[bool]) -> bool {
+        self.clauses.iter().all(|clause| {
+            clause
+                .iter()
+                .any(|literal| values[literal.variable] == literal.positive)
+        })
+    }
+
+    /// Converts the clause set into a conjunction of disjunctions.
+    ///
+    /// Auxiliary variables are converted into atoms with their [names](ClauseSet::variable_name).
+    /// The empty clause set is converted into the constant `T`, the empty clause into the
+    /// constant `F`.
+    pub fn to_proposition(&self) -> Proposition {
+        let prefix = self.auxiliary_prefix();
+        let literal = |literal: &Literal| {
+            let atom = Proposition::Atom(self.name(literal.variable, &prefix));
+            if literal.positive {
+                atom
+            } else {
+                Proposition::Negation(Negation {
+                    inner: Box::new(atom),
+                })
+            }
+        };
+        self.clauses
+            .iter()
+            .map(|clause| {
+                clause
+                    .iter()
+                    .map(literal)
+                    .reduce(|left, right| {
+                        Proposition::Disjunction(Disjunction {
+                            left: Box::new(left),
+                            right: Box::new(right),
+                        })
+                    })
+                    .unwrap_or(Proposition::Constant(false))
+            })
+            .reduce(|left, right| {
+                Proposition::Conjunction(Conjunction {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                })
+            })
+            .unwrap_or(Proposition::Constant(true))
+    }
+
+    // Creates the assignment of the named variables from the values of all variables.
+    pub(crate) fn assignment(&self, values: &[bool]) -> Assignment {
+        self.variables
+            .iter()
+            .zip(values)
+            .map(|(v, b)| (v.clone(), *b))
+            .collect()
+    }
+
+    // Returns a prefix for the names of the auxiliary variables that doesn't clash with the names
+    // of the named variables.
+    pub(crate) fn auxiliary_prefix(&self) -> String {
+        let mut prefix = "t".to_owned();
+        while self.variables.iter().any(|v| {
+            v.strip_prefix(&prefix)
+                .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        }) {
+            prefix.push('_');
+        }
+        prefix
+    }
+
+    fn name(&self, variable: usize, prefix: &str) -> String {
+        match self.variables.get(variable) {
+            Some(name) => name.clone(),
+            None => format!("{prefix}{}", variable - self.variables.len() + 1),
+        }
+    }
+}
+
+impl Display for ClauseSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        if self.clauses.is_empty() {
+            return write!(f, "⊤");
+        }
+        let prefix = self.auxiliary_prefix();
+        for (i, clause) in self.clauses.iter().enumerate() {
+            if i > 0 {
+                write!(f, " & ")?;
+            }
+            if clause.is_empty() {
+                write!(f, "⊥")?;
+                continue;
+            }
+            write!(f, "(")?;
+            for (j, literal) in clause.iter().enumerate() {
+                if j > 0 {
+                    write!(f, " | ")?;
+                }
+                if !literal.positive {
+                    write!(f, "!")?;
+                }
+                write!(f, "{}", self.name(literal.variable, &prefix))?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+// Returns the clauses of the proposition with the given id or of its negation if positive is
+// false.
+fn distribute(arena: &PropositionArena, id: PropId, positive: bool) -> Vec<Vec<Literal>> {
+    match (arena.node(id), positive) {
+        // TRUE is the empty conjunction, FALSE the empty clause
+        (PropNode::Constant(value), _) if value == positive => vec![],
+        (PropNode::Constant(_), _) => vec![vec![]],
+        (PropNode::Atom(v), _) => vec![vec![Literal::new(v, positive)]],
+        (PropNode::Negation(inner), _) => distribute(arena, inner, !positive),
+        (PropNode::Conjunction(left, right), true) => concat(
+            distribute(arena, left, true),
+            distribute(arena, right, true),
+        ),
+        (PropNode::Conjunction(left, right), false) => product(
+            distribute(arena, left, false),
+            distribute(arena, right, false),
+        ),
+        (PropNode::Disjunction(left, right), true) => product(
+            distribute(arena, left, true),
+            distribute(arena, right, true),
+        ),
+        (PropNode::Disjunction(left, right), false) => concat(
+            distribute(arena, left, false),
+            distribute(arena, right, false),
+        ),
+        (PropNode::Implication(left, right), true) => product(
+            distribute(arena, left, false),
+            distribute(arena, right, true),
+        ),
+        (PropNode::Implication(left, right), false) => concat(
+            distribute(arena, left, true),
+            distribute(arena, right, false),
+        ),
+        (PropNode::BiImplication(left, right), _) => {
+            // a <-> b is (!a | b) & (a | !b), its negation is (a | b) & (!a | !b)
+            concat(
+                product(
+                    distribute(arena, left, false),
+                    distribute(arena, right, positive),
+                ),
+                product(
+                    distribute(arena, left, true),
+                    distribute(arena, right, !positive),
+                ),
+            )
+        }
+        (PropNode::ExclusiveDisjunction(left, right), _) => {
+            // a ^ b is (a | b) & (!a | !b), its negation is (a | !b) & (!a | b)
+            concat(
+                product(
+                    distribute(arena, left, true),
+                    distribute(arena, right, positive),
+                ),
+                product(
+                    distribute(arena, left, false),
+                    distribute(arena, right, !positive),
+                ),
+            )
+        }
+        (PropNode::AlternativeDenial(left, right), true) => product(
+            distribute(arena, left, false),
+            distribute(arena, right, false),
+        ),
+        (PropNode::AlternativeDenial(left, right), false) => concat(
+            distribute(arena, left, true),
+            distribute(arena, right, true),
+        ),
+        (PropNode::JointDenial(left, right), true) => concat(
+            distribute(arena, left, false),
+            distribute(arena, right, false),
+        ),
+        (PropNode::JointDenial(left, right), false) => product(
+            distribute(arena, left, true),
+            distribute(arena, right, true),
+        ),
+    }
+}
+
+// Returns the clauses of the conjunction of two clause sets.
+fn concat(mut left: Vec<Vec<Literal>>, right: Vec<Vec<Literal>>) -> Vec<Vec<Literal>> {
+    left.extend(right);
+    left
+}
+
+// Returns the clauses of the disjunction of two clause sets.
+fn product(left: Vec<Vec<Literal>>, right: Vec<Vec<Literal>>) -> Vec<Vec<Literal>> {
+    let mut clauses = Vec::new();
+    for l in &left {
+        for r in &right {
+            let mut clause = l.clone();
+            for literal in r {
+                if !clause.contains(literal) {
+                    clause.push(*literal);
+                }
+            }
+            if !clause.iter().any(|literal| clause.contains(&!*literal)) {
+                clauses.push(clause);
+            }
+        }
+    }
+    clauses
+}
+
+struct TseitinEncoder<'a> {
+    arena: &'a PropositionArena,
+    literals: HashMap<PropId, Literal>,
+    clause_set: ClauseSet,
+}
+
+impl TseitinEncoder<'_> {
+    // Returns the literal that is equivalent to the proposition.
+    // The clauses of the connectives are added by `define`, which keeps the frames of this
+    // recursion small.
+    fn encode(&mut self, id: PropId) -> Literal {
+        if let Some(literal) = self.literals.get(&id) {
+            return *literal;
+        }
+        let node = self.arena.node(id);
+        let literal = match node {
+            PropNode::Constant(value) => {
+                let x = self.auxiliary();
+                self.add([vec![x]]);
+                if value { x } else { !x }
+            }
+            PropNode::Atom(v) => Literal::new(v, true),
+            PropNode::Negation(inner) => !self.encode(inner),
+            PropNode::Implication(left, right)
+            | PropNode::BiImplication(left, right)
+            | PropNode::Disjunction(left, right)
+            | PropNode::Conjunction(left, right)
+            | PropNode::ExclusiveDisjunction(left, right)
+            | PropNode::AlternativeDenial(left, right)
+            | PropNode::JointDenial(left, right) => {
+                let a = self.encode(left);
+                let b = self.encode(right);
+                let x = self.auxiliary();
+                self.define(node, x, a, b)
+            }
+        };
+        self.literals.insert(id, literal);
+        literal
+    }
+
+    // Adds the clauses that make the auxiliary variable x equivalent to the connective applied
+    // to the literals a and b. The denials and the exclusive disjunction are the negations of the
+    // conjunction, the disjunction and the biimplication, so x stands for the latter and its
+    // negation is returned.
+    fn define(&mut self, node: PropNode, x: Literal, a: Literal, b: Literal) -> Literal {
+        match node {
+            PropNode::Implication(..) => {
+                self.add([vec![!x, !a, b], vec![x, a], vec![x, !b]]);
+                x
+            }
+            PropNode::BiImplication(..) | PropNode::ExclusiveDisjunction(..) => {
+                self.add([
+                    vec![!x, !a, b],
+                    vec![!x, a, !b],
+                    vec![x, a, b],
+                    vec![x, !a, !b],
+                ]);
+                if matches!(node, PropNode::BiImplication(..)) {
+                    x
+                } else {
+                    !x
+                }
+            }
+            PropNode::Disjunction(..) | PropNode::JointDenial(..) => {
+                self.add([vec![!x, a, b], vec![x, !a], vec![x, !b]]);
+                if matches!(node, PropNode::Disjunction(..)) {
+                    x
+                } else {
+                    !x
+                }
+            }
+            PropNode::Conjunction(..) | PropNode::AlternativeDenial(..) => {
+                self.add([vec![!x, a], vec![!x, b], vec![x, !a, !b]]);
+                if matches!(node, PropNode::Conjunction(..)) {
+                    x
+                } else {
+                    !x
+                }
+            }
+            PropNode::Constant(_) | PropNode::Atom(_) | PropNode::Negation(_) => {
+                unreachable!("only connectives are defined by auxiliary variables")
+            }
+        }
+    }
+
+    // Introduces a fresh auxiliary variable.
+    fn auxiliary(&mut self) -> Literal {
+        let x = Literal::new(self.clause_set.variable_count, true);
+        self.clause_set.variable_count += 1;
+        x
+    }
+
+    fn add(&mut self, clauses: impl IntoIterator<Item = Vec<Literal>>) {
+        self.clause_set.clauses.extend(clauses);
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    fmt::{Display, Error, Formatter},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use crate::strategy::ExpansionStrategy;
+
+/// The configuration of a [`Prover`]
+///
+/// Besides the [`ExpansionStrategy`] it limits the resources a single call of the prover may use.
+/// All limits are optional. If one of them is exceeded, the prover gives up and returns
+/// [`RaaError::GaveUp`] with the statistics gathered so far.
+///
+/// The limits apply to a whole call like [`Prover::prove`], even if it builds more than one truth
+/// tree.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{
+///     config::{Limit, ProverConfig},
+///     errors::RaaError,
+///     prover::Prover,
+///     proposition::Proposition,
+///     conjunction::Conjunction,
+/// };
+///
+/// // p & q needs three nodes
+/// let proposition = Proposition::Conjunction(Conjunction {
+///     left: Box::new("p".into()),
+///     right: Box::new("q".into()),
+/// });
+/// let prover = Prover::with_config(ProverConfig {
+///     max_nodes: Some(2),
+///     ..Default::default()
+/// });
+/// let Err(RaaError::GaveUp { limit, .. }) = prover.prove(&proposition) else {
+///     panic!("The prover should give up");
+/// };
+/// assert_eq!(limit, Limit::Nodes(2));
+/// ```
+///
+/// [`Prover`]: crate::prover::Prover
+/// [`Prover::prove`]: crate::prover::Prover::prove
+/// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+#[derive(Debug, Clone, Default)]
+pub struct ProverConfig {
+    /// The order in which the nodes of a truth tree are decomposed
+    pub strategy: ExpansionStrategy,
+
+    /// The maximum number of nodes of all truth trees
+    pub max_nodes: Option<usize>,
+
+    /// The maximum number of nodes taken from the agenda of the truth trees
+    pub max_steps: Option<usize>,
+
+    /// The time after which a call of the prover gives up, measured from the start of the call
+    pub timeout: Option<Duration>,
+
+    /// A token to cancel the prover from another thread
+    pub cancellation: Option<CancellationToken>,
+}
+
+impl ProverConfig {
+    /// Sets the time each call of the prover may take.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    // Returns the first limit that is exceeded after the given number of nodes and steps by a call
+    // that started at the given point in time.
+    pub(crate) fn exceeded(&self, nodes: usize, steps: usize, started: Instant) -> Option<Limit> {
+        if self
+            .cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            return Some(Limit::Cancelled);
+        }
+        if let Some(max_nodes) = self.max_nodes
+            && nodes > max_nodes
+        {
+            return Some(Limit::Nodes(max_nodes));
+        }
+        if let Some(max_steps) = self.max_steps
+            && steps > max_steps
+        {
+            return Some(Limit::Steps(max_steps));
+        }
+        if self.timeout.is_some_and(|t| started.elapsed() >= t) {
+            return Some(Limit::Deadline);
+        }
+        None
+    }
+}
+
+/// The limit of a [`ProverConfig`] that made the prover give up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// The truth trees needed more than the given number of nodes
+    Nodes(usize),
+
+    /// The proof took more than the given number of nodes from the agenda
+    Steps(usize),
+
+    /// The call took longer than the timeout
+    Deadline,
+
+    /// The proof has been cancelled by its [`CancellationToken`]
+    Cancelled,
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match self {
+            Limit::Nodes(max) => write!(f, "more than {max} nodes needed"),
+            Limit::Steps(max) => write!(f, "more than {max} steps needed"),
+            Limit::Deadline => write!(f, "deadline exceeded"),
+            Limit::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+/// A token to cancel a running proof
+///
+/// Clones of a token share their state, so a token can be handed to the prover and cancelled from
+/// another thread. The prover checks the token at each step.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::config::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let clone = token.clone();
+/// std::thread::spawn(move || clone.cancel()).join().unwrap();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new token that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels all proofs that use this token or one of its clones.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
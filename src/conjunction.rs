@@ -1,7 +1,7 @@
 use crate::proposition::Proposition;
 use std::fmt::{Debug, Display, Error, Formatter};
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 /// Conjunction struct represents a binary logical conjunction operation.
 pub struct Conjunction {
     pub left: Box<Proposition>,
@@ -0,0 +1,168 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{
+    cnf::{ClauseSet, Literal},
+    errors::{RaaError, Result},
+};
+
+impl ClauseSet {
+    /// Returns the number of assignments of all variables that satisfy the clause set (#SAT).
+    ///
+    /// The models are counted without enumerating them. The counter splits the clauses by the
+    /// values of single variables like a DPLL solver, counts the clauses without common variables
+    /// independently and multiplies the results, and remembers the counts of the clause sets it
+    /// has already seen. Variables that don't occur in any clause double the count. Thus the
+    /// number of variables is not limited like for truth tables.
+    ///
+    /// Each model of a proposition has exactly one extension to the auxiliary variables of its
+    /// [Tseitin encoding](crate::proposition::Proposition::to_tseitin_cnf), so both have the same
+    /// number of models.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::cnf::ClauseSet;
+    ///
+    /// // (p | q) & (r)
+    /// let clause_set = ClauseSet::from_dimacs("p cnf 3 2\n1 2 0\n3 0\n").unwrap();
+    /// assert_eq!(clause_set.count_models().unwrap(), 3);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError::TooManyModels`] if the number of models exceeds [`u128::MAX`].
+    pub fn count_models(&self) -> Result<u128> {
+        // Tautological clauses are satisfied by all assignments.
+        let clauses = self
+            .clauses
+            .iter()
+            .filter(|clause| !clause.iter().any(|l| clause.contains(&!*l)))
+            .map(|clause| clause.iter().copied().collect::<BTreeSet<_>>())
+            .map(|clause| clause.into_iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut counter = Counter {
+            cache: HashMap::new(),
+        };
+        counter
+            .count(clauses, self.variable_count)
+            .ok_or(RaaError::TooManyModels {
+                variables: self.variable_count,
+            })
+    }
+}
+
+// A model counter that remembers the counts of the components it has counted. Counts are None
+// on overflow.
+struct Counter {
+    cache: HashMap<Vec<Vec<Literal>>, Option<u128>>,
+}
+
+impl Counter {
+    // Returns the number of models of the clauses over the given number of variables, which
+    // include the variables of the clauses.
+    fn count(&mut self, clauses: Vec<Vec<Literal>>, variables: usize) -> Option<u128> {
+        let Some((clauses, assigned)) = propagate(clauses) else {
+            return Some(0);
+        };
+        let free = variables - assigned - variables_of(&clauses).len();
+        let counts = components(clauses)
+            .into_iter()
+            .map(|component| self.count_component(component))
+            .collect::<Vec<_>>();
+        // An unsatisfiable component makes an overflow elsewhere irrelevant.
+        if counts.contains(&Some(0)) {
+            return Some(0);
+        }
+        counts.into_iter().try_fold(
+            1u128.checked_shl(u32::try_from(free).ok()?)?,
+            |result, count| result.checked_mul(count?),
+        )
+    }
+
+    // Returns the number of models of connected clauses over their variables.
+    fn count_component(&mut self, mut component: Vec<Vec<Literal>>) -> Option<u128> {
+        component.sort();
+        if let Some(count) = self.cache.get(&component) {
+            return *count;
+        }
+        let variables = variables_of(&component).len();
+        // The most frequent variable splits the component most likely.
+        let mut occurrences = HashMap::new();
+        for literal in component.iter().flatten() {
+            *occurrences.entry(literal.variable).or_insert(0) += 1;
+        }
+        let variable = occurrences
+            .into_iter()
+            .max_by_key(|(variable, count)| (*count, std::cmp::Reverse(*variable)))
+            .map(|(variable, _)| variable)
+            .expect("components are not empty");
+        let positive = self.count(
+            assign(&component, Literal::new(variable, true)),
+            variables - 1,
+        );
+        let negative = self.count(
+            assign(&component, Literal::new(variable, false)),
+            variables - 1,
+        );
+        let count = positive.zip(negative).and_then(|(p, n)| p.checked_add(n));
+        self.cache.insert(component, count);
+        count
+    }
+}
+
+// Makes the literal TRUE: removes the satisfied clauses and the complementary literal.
+fn assign(clauses: &[Vec<Literal>], literal: Literal) -> Vec<Vec<Literal>> {
+    clauses
+        .iter()
+        .filter(|clause| !clause.contains(&literal))
+        .map(|clause| clause.iter().copied().filter(|l| *l != !literal).collect())
+        .collect()
+}
+
+// Assigns the literals of unit clauses until no unit clause is left. Returns the remaining
+// clauses and the number of assigned variables, or None on a conflict.
+fn propagate(mut clauses: Vec<Vec<Literal>>) -> Option<(Vec<Vec<Literal>>, usize)> {
+    let mut assigned = 0;
+    loop {
+        if clauses.iter().any(Vec::is_empty) {
+            return None;
+        }
+        let Some(unit) = clauses.iter().find(|clause| clause.len() == 1) else {
+            return Some((clauses, assigned));
+        };
+        clauses = assign(&clauses, unit[0]);
+        assigned += 1;
+    }
+}
+
+// Splits the clauses into groups that share no variables.
+fn components(clauses: Vec<Vec<Literal>>) -> Vec<Vec<Vec<Literal>>> {
+    // Union-find over the variables, each clause joins its variables.
+    let mut parents: HashMap<usize, usize> = HashMap::new();
+    fn find(parents: &mut HashMap<usize, usize>, variable: usize) -> usize {
+        let parent = *parents.entry(variable).or_insert(variable);
+        if parent == variable {
+            return variable;
+        }
+        let root = find(parents, parent);
+        parents.insert(variable, root);
+        root
+    }
+    for clause in &clauses {
+        let first = find(&mut parents, clause[0].variable);
+        for literal in &clause[1..] {
+            let root = find(&mut parents, literal.variable);
+            parents.insert(root, first);
+        }
+    }
+    let mut components: HashMap<usize, Vec<Vec<Literal>>> = HashMap::new();
+    for clause in clauses {
+        let root = find(&mut parents, clause[0].variable);
+        components.entry(root).or_default().push(clause);
+    }
+    components.into_values().collect()
+}
+
+fn variables_of(clauses: &[Vec<Literal>]) -> BTreeSet<usize> {
+    clauses.iter().flatten().map(|l| l.variable).collect()
+}
@@ -0,0 +1,157 @@
+use crate::{
+    assignment::Assignment,
+    cnf::ClauseSet,
+    errors::Result,
+    negation::Negation,
+    proposition::Proposition,
+    prover::{ProveResult, Prover},
+    table_generator::TableGenerator,
+};
+
+/// A decision procedure for propositions
+///
+/// The crate contains several engines with different strengths: the tableau [`Prover`] explains
+/// its results with truth trees, the [`TableGenerator`] enumerates all assignments of up to 16
+/// variables and the [`DpllSolver`] scales to propositions with many variables. This trait allows
+/// applications to choose the engine at runtime, for instance by the size of a proposition.
+///
+/// Only [`Decider::find_model`] needs to be implemented. The classification is derived from the
+/// models of the proposition and its negation.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{
+///     decider::Decider,
+///     dpll::DpllSolver,
+///     prover::{Prover, ProveResult},
+///     proposition::Proposition,
+///     table_generator::TableGenerator,
+///     implication::Implication,
+/// };
+///
+/// // P → Q
+/// let proposition = Proposition::Implication(Implication {
+///     left: Box::new("P".into()),
+///     right: Box::new("Q".into()),
+/// });
+///
+/// let deciders: [Box<dyn Decider>; 3] = [
+///     Box::new(Prover::new()),
+///     Box::new(TableGenerator::new()),
+///     Box::new(DpllSolver::new()),
+/// ];
+/// for decider in deciders {
+///     assert_eq!(decider.classify(&proposition).unwrap(), ProveResult::Contingent);
+///     let model = decider.find_model(&proposition).unwrap().unwrap();
+///     assert!(proposition.evaluate(&model).unwrap());
+/// }
+/// ```
+///
+/// [`DpllSolver`]: crate::dpll::DpllSolver
+pub trait Decider {
+    /// Classifies a proposition as tautology ([`ProveResult::Proven`]), contradiction
+    /// ([`ProveResult::Falsified`]) or [`ProveResult::Contingent`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if the proposition can't be decided by this engine.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    fn classify(&self, proposition: &Proposition) -> Result<ProveResult> {
+        let negation = Proposition::Negation(Negation {
+            inner: Box::new(proposition.clone()),
+        });
+        if self.find_model(&negation)?.is_none() {
+            Ok(ProveResult::Proven)
+        } else if self.find_model(proposition)?.is_none() {
+            Ok(ProveResult::Falsified)
+        } else {
+            Ok(ProveResult::Contingent)
+        }
+    }
+
+    /// Returns true if the proposition is TRUE under at least one assignment.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if the proposition can't be decided by this engine.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    fn is_satisfiable(&self, proposition: &Proposition) -> Result<bool> {
+        Ok(self.find_model(proposition)?.is_some())
+    }
+
+    /// Returns an assignment of all variables of the proposition under which it is TRUE or
+    /// `None` if the proposition is unsatisfiable.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if the proposition can't be decided by this engine.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    fn find_model(&self, proposition: &Proposition) -> Result<Option<Assignment>>;
+
+    /// Returns a model of a proposition that is also given as clauses, or `None` if it is
+    /// unsatisfiable.
+    ///
+    /// The clause set is satisfiable exactly if the proposition is, and the values of its named
+    /// variables form a model of the proposition. [`Models::new`] keeps both and adds a clause
+    /// that blocks each found model to them. The default implementation decides the proposition,
+    /// the SAT solvers decide the clauses without encoding the proposition again.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if the proposition can't be decided by this engine.
+    ///
+    /// [`Models::new`]: crate::models::Models::new
+    /// [`RaaError`]: crate::errors::RaaError
+    fn find_model_with_clauses(
+        &self,
+        proposition: &Proposition,
+        _clause_set: &ClauseSet,
+    ) -> Result<Option<Assignment>> {
+        self.find_model(proposition)
+    }
+}
+
+impl Decider for Prover {
+    fn classify(&self, proposition: &Proposition) -> Result<ProveResult> {
+        self.prove(proposition)
+    }
+
+    fn find_model(&self, proposition: &Proposition) -> Result<Option<Assignment>> {
+        Prover::is_satisfiable(self, proposition)
+    }
+}
+
+impl Decider for TableGenerator {
+    fn classify(&self, proposition: &Proposition) -> Result<ProveResult> {
+        let truth_table = self.generate_truth_table(proposition)?;
+        let is_true = |line: &Vec<bool>| line.last() == Some(&true);
+        Ok(if truth_table.lines.iter().all(is_true) {
+            ProveResult::Proven
+        } else if !truth_table.lines.iter().any(is_true) {
+            ProveResult::Falsified
+        } else {
+            ProveResult::Contingent
+        })
+    }
+
+    fn find_model(&self, proposition: &Proposition) -> Result<Option<Assignment>> {
+        let truth_table = self.generate_truth_table(proposition)?;
+        Ok(truth_table
+            .lines
+            .iter()
+            .find(|line| line.last() == Some(&true))
+            .map(|line| {
+                truth_table
+                    .header
+                    .iter()
+                    .zip(line)
+                    .take(line.len() - 1)
+                    .map(|(v, b)| (v.clone(), *b))
+                    .collect()
+            }))
+    }
+}
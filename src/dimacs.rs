@@ -0,0 +1,244 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{
+    cnf::{ClauseSet, Literal},
+    errors::{RaaError, Result},
+};
+
+impl ClauseSet {
+    /// Reads a clause set in the DIMACS CNF format of SAT solvers.
+    ///
+    /// The input starts with the problem line `p cnf <variables> <clauses>`, followed by the
+    /// clauses. Each clause is a list of non-zero numbers that is terminated by `0`, a negative
+    /// number is a negated variable. Lines starting with `c` are comments, a comment of the form
+    /// `c var <number> <name>` names a variable. The names of the other variables are `x1`, `x2`
+    /// and so on, with underscores appended to `x` if these names are already taken. This also
+    /// applies to variables named `not`, `and`, `or`, `xor`, `nand`, `nor`, `NAND`, `NOR`, `true`,
+    /// `false`, `T` or `F`, which the parser reads as operators and constants. Other names that the parser
+    /// doesn't accept as variables are rejected. A line starting with `%` ends the input.
+    ///
+    /// All variables of the result are named variables, auxiliary variables of a
+    /// [Tseitin encoding](crate::proposition::Proposition::to_tseitin_cnf) can't be told apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::cnf::ClauseSet;
+    ///
+    /// let input = "c var 1 p\np cnf 2 2\n1 -2 0\n2 0\n";
+    /// let clause_set = ClauseSet::from_dimacs(input).unwrap();
+    /// assert_eq!(clause_set.variables, vec!["p", "x2"]);
+    /// assert_eq!(clause_set.to_string(), "(p | !x2) & (x2)");
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError::InvalidDimacs`] with the line number if the input is malformed, e.g.
+    /// if a clause refers to an undeclared variable or the number of clauses doesn't match the
+    /// problem line. The problem line may declare at most 2^20 variables, each of which gets a
+    /// name.
+    pub fn from_dimacs(input: &str) -> Result<Self> {
+        let error = |line: usize, message: String| RaaError::InvalidDimacs { line, message };
+        let mut header = None;
+        let mut names = BTreeMap::new();
+        let mut clauses = Vec::new();
+        let mut clause = Vec::new();
+        let mut line_number = 0;
+        for line in input.lines() {
+            line_number += 1;
+            let line = line.trim();
+            if line.starts_with('%') {
+                break;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix('c')
+                && (comment.is_empty() || comment.starts_with(char::is_whitespace))
+            {
+                if let ["var", variable, name] = comment.split_whitespace().collect::<Vec<_>>()[..]
+                {
+                    let variable = variable
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|v| *v > 0)
+                        .ok_or_else(|| {
+                            error(line_number, format!("invalid variable {variable}"))
+                        })?;
+                    // A variable named like an operator or a constant keeps its default name.
+                    if RESERVED_WORDS.contains(&name) {
+                        continue;
+                    }
+                    if !is_variable_name(name) {
+                        Err(error(line_number, format!("invalid variable name {name}")))?;
+                    }
+                    if names.insert(variable, name.to_owned()).is_some() {
+                        Err(error(
+                            line_number,
+                            format!("variable {variable} named twice"),
+                        ))?;
+                    }
+                }
+                continue;
+            }
+            if let Some(problem) = line.strip_prefix('p')
+                && (problem.is_empty() || problem.starts_with(char::is_whitespace))
+            {
+                if header.is_some() {
+                    Err(error(line_number, "duplicate problem line".to_owned()))?;
+                }
+                let ["cnf", variables, clauses] =
+                    problem.split_whitespace().collect::<Vec<_>>()[..]
+                else {
+                    Err(error(
+                        line_number,
+                        "expected the problem line p cnf <variables> <clauses>".to_owned(),
+                    ))?
+                };
+                let count = |field: &str| {
+                    field
+                        .parse::<usize>()
+                        .map_err(|_| error(line_number, format!("invalid number {field}")))
+                };
+                let variables = count(variables)?;
+                if variables > MAX_VARIABLES {
+                    Err(error(
+                        line_number,
+                        format!("{variables} variables exceed the maximum of {MAX_VARIABLES}"),
+                    ))?;
+                }
+                header = Some((variables, count(clauses)?));
+                continue;
+            }
+            let Some((variable_count, _)) = header else {
+                Err(error(
+                    line_number,
+                    "clause before the problem line".to_owned(),
+                ))?
+            };
+            for token in line.split_whitespace() {
+                let literal = token
+                    .parse::<i64>()
+                    .map_err(|_| error(line_number, format!("invalid literal {token}")))?;
+                if literal == 0 {
+                    clauses.push(std::mem::take(&mut clause));
+                    continue;
+                }
+                let variable = literal.unsigned_abs() as usize;
+                if variable > variable_count {
+                    Err(error(
+                        line_number,
+                        format!(
+                            "variable {variable} exceeds the {variable_count} declared variables"
+                        ),
+                    ))?;
+                }
+                clause.push(Literal::new(variable - 1, literal > 0));
+            }
+        }
+        let Some((variable_count, clause_count)) = header else {
+            Err(error(line_number, "missing problem line".to_owned()))?
+        };
+        // The terminating 0 of the last clause is optional.
+        if !clause.is_empty() {
+            clauses.push(clause);
+        }
+        if clauses.len() != clause_count {
+            Err(error(
+                line_number,
+                format!("{clause_count} clauses declared, {} found", clauses.len()),
+            ))?;
+        }
+        if let Some((variable, _)) = names.range(variable_count + 1..).next() {
+            Err(error(
+                line_number,
+                format!(
+                    "named variable {variable} exceeds the {variable_count} declared variables"
+                ),
+            ))?;
+        }
+        let mut prefix = "x".to_owned();
+        while names.values().any(|name| {
+            name.strip_prefix(&prefix)
+                .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        }) {
+            prefix.push('_');
+        }
+        let mut known = HashSet::new();
+        let mut variables = Vec::with_capacity(variable_count);
+        for variable in 1..=variable_count {
+            let name = names
+                .remove(&variable)
+                .unwrap_or_else(|| format!("{prefix}{variable}"));
+            if !known.insert(name.clone()) {
+                Err(error(
+                    line_number,
+                    format!("variable name {name} used twice"),
+                ))?;
+            }
+            variables.push(name);
+        }
+        Ok(ClauseSet {
+            variables,
+            variable_count,
+            clauses,
+        })
+    }
+
+    /// Writes the clause set in the DIMACS CNF format of SAT solvers.
+    ///
+    /// The variable with index `i` is written as number `i + 1`. The names of the variables are
+    /// written as comments of the form `c var <number> <name>`, auxiliary variables are not
+    /// named. Thus [`ClauseSet::from_dimacs`] restores the variable names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{proposition::Proposition, implication::Implication};
+    ///
+    /// // p -> q
+    /// let proposition = Proposition::Implication(Implication {
+    ///     left: Box::new("p".into()),
+    ///     right: Box::new("q".into()),
+    /// });
+    /// assert_eq!(
+    ///     proposition.to_cnf().unwrap().to_dimacs(),
+    ///     "c var 1 p\nc var 2 q\np cnf 2 1\n-1 2 0\n"
+    /// );
+    /// ```
+    pub fn to_dimacs(&self) -> String {
+        let mut dimacs = String::new();
+        for (i, name) in self.variables.iter().enumerate() {
+            dimacs.push_str(&format!("c var {} {name}\n", i + 1));
+        }
+        dimacs.push_str(&format!(
+            "p cnf {} {}\n",
+            self.variable_count,
+            self.clauses.len()
+        ));
+        for clause in &self.clauses {
+            for literal in clause {
+                let sign = if literal.positive { "" } else { "-" };
+                dimacs.push_str(&format!("{sign}{} ", literal.variable + 1));
+            }
+            dimacs.push_str("0\n");
+        }
+        dimacs
+    }
+}
+
+// The maximum number of variables of a problem line. Every variable is named and the solvers
+// allocate their state for all of them, so a larger number would exhaust the memory.
+const MAX_VARIABLES: usize = 1 << 20;
+
+// The names of the operators and constants that consist of letters only.
+const RESERVED_WORDS: [&str; 12] = [
+    "not", "and", "or", "xor", "nand", "nor", "NAND", "NOR", "true", "false", "T", "F",
+];
+
+// Returns true if the parser accepts the name as a variable.
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+}
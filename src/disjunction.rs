@@ -1,7 +1,7 @@
 use crate::proposition::Proposition;
 use std::fmt::{Debug, Display, Error, Formatter};
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 /// The disjunction structure represents a binary logical disjunction operation.
 pub struct Disjunction {
     pub left: Box<Proposition>,
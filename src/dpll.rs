@@ -0,0 +1,217 @@
+use crate::{
+    assignment::Assignment,
+    cnf::{ClauseSet, Literal},
+    decider::Decider,
+    errors::Result,
+    models::Models,
+    proposition::Proposition,
+};
+
+/// A satisfiability solver based on the Davis–Putnam–Logemann–Loveland procedure
+///
+/// The proposition is converted into a set of clauses with the Tseitin transformation. The solver
+/// then assigns the variables one after another, propagates unit clauses and backtracks on
+/// conflicts. Its memory usage is linear in the size of the proposition, hence it handles
+/// propositions with far more variables than a truth table.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{
+///     decider::Decider,
+///     dpll::DpllSolver,
+///     prover::ProveResult,
+///     proposition::Proposition,
+///     disjunction::Disjunction,
+///     negation::Negation,
+/// };
+///
+/// // P ∨ ¬P
+/// let proposition = Proposition::Disjunction(Disjunction {
+///     left: Box::new("P".into()),
+///     right: Box::new(Proposition::Negation(Negation {
+///         inner: Box::new("P".into()),
+///     })),
+/// });
+///
+/// let solver = DpllSolver::new();
+/// assert_eq!(solver.classify(&proposition).unwrap(), ProveResult::Proven);
+/// ```
+#[derive(Debug, Default)]
+pub struct DpllSolver {}
+
+impl DpllSolver {
+    /// Creates a new instance of the DpllSolver.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns the values of all variables of a satisfying assignment, if there is one.
+    // Variables that don't matter are set to false.
+    pub(crate) fn solve(&self, clause_set: &ClauseSet) -> Option<Vec<bool>> {
+        let mut values = vec![None; clause_set.variable_count];
+        search(&clause_set.clauses, &mut values)
+            .then(|| values.into_iter().map(|v| v.unwrap_or(false)).collect())
+    }
+
+    /// Returns a lazy enumeration of the models of a proposition.
+    ///
+    /// The solver is restarted for each model with the clauses that block the previous models,
+    /// like [`Models::new`] does for any [`Decider`]. See [`Models`] for projections and limits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{dpll::DpllSolver, proposition::Proposition, disjunction::Disjunction};
+    ///
+    /// // P ∨ Q
+    /// let proposition = Proposition::Disjunction(Disjunction {
+    ///     left: Box::new("P".into()),
+    ///     right: Box::new("Q".into()),
+    /// });
+    ///
+    /// assert_eq!(DpllSolver::new().models(&proposition).count(), 3);
+    /// ```
+    pub fn models(&self, proposition: &Proposition) -> Models<'_> {
+        Models::new(self, proposition)
+    }
+}
+
+impl Decider for DpllSolver {
+    fn find_model(&self, proposition: &Proposition) -> Result<Option<Assignment>> {
+        let clause_set = ClauseSet::tseitin(proposition)?;
+        Ok(self
+            .solve(&clause_set)
+            .map(|values| clause_set.assignment(&values)))
+    }
+
+    fn find_model_with_clauses(
+        &self,
+        _proposition: &Proposition,
+        clause_set: &ClauseSet,
+    ) -> Result<Option<Assignment>> {
+        Ok(self
+            .solve(clause_set)
+            .map(|values| clause_set.assignment(&values)))
+    }
+}
+
+// Propagates unit clauses and then branches on a literal of an unsatisfied clause.
+// On failure all assignments made by this call are undone.
+fn search(clauses: &[Vec<Literal>], values: &mut [Option<bool>]) -> bool {
+    let mut trail = Vec::new();
+    if propagate(clauses, values, &mut trail) {
+        let Some(literal) = choose_literal(clauses, values) else {
+            // All clauses are satisfied.
+            return true;
+        };
+        for value in [literal.positive, !literal.positive] {
+            values[literal.variable] = Some(value);
+            if search(clauses, values) {
+                return true;
+            }
+        }
+        values[literal.variable] = None;
+    }
+    for variable in trail {
+        values[variable] = None;
+    }
+    false
+}
+
+// Assigns the remaining literal of each unit clause until no unit clause is left.
+// Returns false if a clause is falsified. The assigned variables are recorded on the trail.
+fn propagate(
+    clauses: &[Vec<Literal>],
+    values: &mut [Option<bool>],
+    trail: &mut Vec<usize>,
+) -> bool {
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for clause in clauses {
+            let mut unassigned = None;
+            let mut unassigned_count = 0;
+            let mut satisfied = false;
+            for literal in clause {
+                match literal.value(values) {
+                    Some(true) => {
+                        satisfied = true;
+                        break;
+                    }
+                    Some(false) => (),
+                    None => {
+                        unassigned = Some(*literal);
+                        unassigned_count += 1;
+                    }
+                }
+            }
+            if satisfied {
+                continue;
+            }
+            match (unassigned, unassigned_count) {
+                (None, _) => return false,
+                (Some(literal), 1) => {
+                    values[literal.variable] = Some(literal.positive);
+                    trail.push(literal.variable);
+                    changed = true;
+                }
+                _ => (),
+            }
+        }
+    }
+    true
+}
+
+// Returns an unassigned literal of the shortest clause that isn't satisfied yet.
+fn choose_literal(clauses: &[Vec<Literal>], values: &[Option<bool>]) -> Option<Literal> {
+    clauses
+        .iter()
+        .filter(|clause| !clause.iter().any(|l| l.value(values) == Some(true)))
+        .min_by_key(|clause| clause.iter().filter(|l| l.value(values).is_none()).count())
+        .and_then(|clause| clause.iter().find(|l| l.value(values).is_none()).copied())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cnf::{ClauseSet, Literal},
+        dpll::DpllSolver,
+    };
+
+    #[test]
+    fn test_pigeonhole() {
+        // Three pigeons in two holes, variable 2 * pigeon + hole
+        let pigeon = |p: usize| vec![Literal::new(2 * p, true), Literal::new(2 * p + 1, true)];
+        let mut clauses = (0..3).map(pigeon).collect::<Vec<_>>();
+        for hole in 0..2 {
+            for p in 0..3 {
+                for q in p + 1..3 {
+                    clauses.push(vec![
+                        Literal::new(2 * p + hole, false),
+                        Literal::new(2 * q + hole, false),
+                    ]);
+                }
+            }
+        }
+        let clause_set = ClauseSet {
+            variables: Vec::new(),
+            variable_count: 6,
+            clauses,
+        };
+        assert!(DpllSolver::new().solve(&clause_set).is_none());
+
+        // Without the third pigeon there is a solution
+        let clause_set = ClauseSet {
+            clauses: clause_set
+                .clauses
+                .into_iter()
+                .filter(|c| c.iter().all(|l| l.variable < 4))
+                .collect(),
+            ..clause_set
+        };
+        let values = DpllSolver::new().solve(&clause_set).unwrap();
+        assert_ne!(values[0], values[2]);
+        assert_ne!(values[1], values[3]);
+    }
+}
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::{config::Limit, stats::ProofStats};
+
 pub type Result<T> = std::result::Result<T, RaaError>;
 
 #[derive(Error, Debug)]
@@ -15,7 +17,7 @@ pub enum RaaError {
         Suggestions:\n\
         • Break down complex propositions into smaller sub-expressions\n\
         • Use logical equivalences to simplify the formula\n\
-        • Consider using a SAT solver for propositions with many variables\n\
+        • Consider using a SAT solver like the CdclSolver (--engine cdcl) for propositions with many variables\n\
         • For educational purposes, focus on propositions with fewer variables"
     )]
     TooManyVariables {
@@ -26,6 +28,17 @@ pub enum RaaError {
     },
     #[error("Variable {name} not defined")]
     UndefinedVariable { name: String },
+    #[error(
+        "The prover gave up: {limit} (after {} steps with {} nodes in {} milliseconds)",
+        .stats.steps,
+        .stats.nodes,
+        .stats.elapsed.as_millis()
+    )]
+    GaveUp { limit: Limit, stats: ProofStats },
+    #[error("Invalid DIMACS input in line {line}: {message}")]
+    InvalidDimacs { line: usize, message: String },
+    #[error("The number of models of the {variables} variables exceeds the maximum of 2^128 - 1")]
+    TooManyModels { variables: usize },
     #[error(transparent)]
     FormatError { source: std::fmt::Error },
 }
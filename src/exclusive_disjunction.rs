@@ -0,0 +1,15 @@
+use crate::proposition::Proposition;
+use std::fmt::{Debug, Display, Error, Formatter};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+/// ExclusiveDisjunction struct represents a binary logical exclusive disjunction (XOR) operation.
+pub struct ExclusiveDisjunction {
+    pub left: Box<Proposition>,
+    pub right: Box<Proposition>,
+}
+
+impl Display for ExclusiveDisjunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        write!(f, "({} ^ {})", self.left, self.right)
+    }
+}
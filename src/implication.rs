@@ -1,7 +1,7 @@
 use crate::proposition::Proposition;
 use std::fmt::{Debug, Display, Error, Formatter};
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 /// Implication struct represents a binary logical implication operation.
 pub struct Implication {
     pub left: Box<Proposition>,
@@ -0,0 +1,15 @@
+use crate::proposition::Proposition;
+use std::fmt::{Debug, Display, Error, Formatter};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+/// JointDenial struct represents a binary logical joint denial (NOR) operation.
+pub struct JointDenial {
+    pub left: Box<Proposition>,
+    pub right: Box<Proposition>,
+}
+
+impl Display for JointDenial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        write!(f, "({} NOR {})", self.left, self.right)
+    }
+}
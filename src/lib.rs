@@ -8,11 +8,13 @@
 //! ## Features
 //!
 //! - **Logical Operators**: Support for all standard propositional logic operators including
-//!   conjunction, disjunction, implication, bi-implication, and negation
+//!   conjunction, disjunction, implication, bi-implication, negation, exclusive disjunction (XOR),
+//!   alternative denial (NAND) and joint denial (NOR)
 //! - **Proposition System**: Flexible proposition representation and manipulation
 //! - **Theorem Prover**: Automated reasoning engine for validating logical arguments
 //! - **Truth Table Generation**: Comprehensive truth table construction and analysis
-//! - **Grammar Parsing**: Built-in parser for logical expressions using the RaaTT grammar
+//! - **Grammar Parsing**: Built-in parser for logical expressions using the RaaTT grammar, which
+//!   accepts ASCII, Unicode and word notations of the operators
 //!
 //! ## Example
 //!
@@ -39,18 +41,50 @@
 //! }
 //! ```
 
+/// Module with the AlternativeDenial struct and its implementation.
+pub mod alternative_denial;
+/// Module with the PropositionArena struct and its implementation.
+pub mod arena;
+/// Module with the Argument struct and its implementation.
+pub mod argument;
+/// Module with the Assignment struct and its implementation.
+pub mod assignment;
 /// Module with the BiImplication struct and its implementation.
 pub mod bi_implication;
+/// Module with the CdclSolver struct and its implementation.
+pub mod cdcl;
+/// Module with the ClauseSet struct that holds propositions in conjunctive normal form.
+pub mod cnf;
+/// Module with the ProverConfig struct that limits the resources of the prover.
+pub mod config;
 /// Module with the Conjunction struct and its implementation.
 pub mod conjunction;
+/// Module with the model counter of the ClauseSet struct.
+pub mod counter;
+/// Module with the Decider trait that abstracts the decision procedures.
+pub mod decider;
+/// Module with the DIMACS reader and writer of the ClauseSet struct.
+pub mod dimacs;
 /// Module with the Disjunction struct and its implementation.
 pub mod disjunction;
+/// Module with the DpllSolver struct and its implementation.
+pub mod dpll;
 /// Module with the Errors enum and its implementation.
 pub mod errors;
+/// Module with the ExclusiveDisjunction struct and its implementation.
+pub mod exclusive_disjunction;
 /// Module with the Implication struct and its implementation.
 pub mod implication;
+/// Module with the JointDenial struct and its implementation.
+pub mod joint_denial;
+/// Module with the Models iterator that enumerates the models of a proposition.
+pub mod models;
 /// Module with the Negation struct and its implementation.
 pub mod negation;
+/// Module with the Notation enum that selects the symbols of the output.
+pub mod notation;
+/// Module with the PrettyPrinter struct that prints propositions with minimal parentheses.
+pub mod pretty;
 /// Module with the Proposition struct and its implementation.
 pub mod proposition;
 /// Module with the prover implementation.
@@ -61,7 +95,13 @@ pub mod raa_tt_grammar;
 mod raa_tt_grammar_trait;
 /// Module with the RaaTtParser struct generated by Parol.
 pub mod raa_tt_parser;
+/// Module with the ProofStats struct that describes the work of the prover.
+pub mod stats;
+/// Module with the ExpansionStrategy enum that controls the order of rule applications.
+pub mod strategy;
 /// Module with the table generator functionality.
 pub mod table_generator;
+/// Module with the Tableau struct that represents a finished truth tree.
+pub mod tableau;
 /// Module with the TruthTable struct and its implementation.
 pub mod truth_table;
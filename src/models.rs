@@ -0,0 +1,487 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use crate::{
+    arena::{PropId, PropositionArena},
+    assignment::Assignment,
+    cdcl::Search,
+    cnf::{ClauseSet, Literal},
+    conjunction::Conjunction,
+    decider::Decider,
+    disjunction::Disjunction,
+    errors::Result,
+    negation::Negation,
+    proposition::Proposition,
+    prover::Prover,
+    table_generator::TableGenerator,
+};
+
+/// A lazy enumeration of the models of a proposition
+///
+/// Each item is a distinct assignment under which the proposition is TRUE. The models are
+/// computed one at a time, so an enumeration can be stopped early without paying for the
+/// remaining models.
+///
+/// There are four ways to find the models:
+/// - [`TableGenerator::models`] walks through the rows of the truth table in their usual order.
+///   It is limited to 16 variables.
+/// - [`Prover::models`] develops the truth tree of the proposition and reads the models off its
+///   open branches.
+/// - [`Models::new`] asks a [`Decider`] for a model, blocks it by adding a clause with the
+///   negation of the model and repeats until no model is left. The SAT solvers decide the Tseitin
+///   clauses of the proposition, the other engines the proposition itself. This suits the SAT
+///   solvers, which find single models of large propositions fast.
+/// - [`CdclSolver::models`](crate::cdcl::CdclSolver::models) blocks the models like [`Models::new`], but continues a single
+///   search and thus keeps the clauses it has learned.
+///
+/// The models can be projected onto a subset of the variables with [`Models::project`]. Then
+/// each assignment of these variables that can be extended to a model is yielded once. Variables
+/// that don't occur in the proposition can take both values.
+///
+/// If an engine fails, e.g. because a limit of the prover is exceeded, the error is yielded and
+/// the enumeration ends.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{prover::Prover, proposition::Proposition, disjunction::Disjunction};
+///
+/// // p | q has three models, two of them after projecting onto p
+/// let proposition = Proposition::Disjunction(Disjunction {
+///     left: Box::new("p".into()),
+///     right: Box::new("q".into()),
+/// });
+///
+/// let prover = Prover::new();
+/// assert_eq!(prover.models(&proposition).count(), 3);
+/// assert_eq!(prover.models(&proposition).project(["p"]).count(), 2);
+///
+/// let model = prover.models(&proposition).limit(1).next().unwrap().unwrap();
+/// assert!(proposition.evaluate(&model).unwrap());
+/// ```
+///
+pub struct Models<'a> {
+    source: Source<'a>,
+    projection: Option<BTreeSet<String>>,
+    limit: Option<usize>,
+    found: usize,
+    finished: bool,
+}
+
+enum Source<'a> {
+    // The found models are blocked by clauses that are added to the encoded proposition.
+    Blocking {
+        decider: &'a dyn Decider,
+        proposition: Proposition,
+        blocking: Option<Blocking>,
+    },
+    // The found models are blocked by clauses that are added to a running CDCL search, which is
+    // None once no model is left.
+    Incremental {
+        proposition: Proposition,
+        search: Option<(Blocking, Option<Search>)>,
+    },
+    // The truth tree is developed when the first model is requested.
+    Branches {
+        prover: &'a Prover,
+        proposition: Proposition,
+        branches: Option<Branches>,
+    },
+    // The rows are enumerated once the projection is known.
+    TruthTable {
+        proposition: Proposition,
+        rows: Option<Rows>,
+    },
+}
+
+// The proposition and its clauses, both extended by the clauses that block the found models,
+// and the variable indices of the projected variables.
+struct Blocking {
+    proposition: Proposition,
+    clause_set: ClauseSet,
+    variables: Vec<(String, usize)>,
+}
+
+// The state of an enumeration of the open branches of a truth tree. The projected variables
+// that are not fixed by the literals of a branch take all combinations of values, which are
+// counted in binary with the first free variable as the most significant digit. Each model is
+// yielded by the first branch that admits it, so nothing is remembered about the found models.
+struct Branches {
+    variables: Vec<String>,
+    branches: Vec<BTreeMap<String, bool>>,
+    branch: usize,
+    // The values of the free variables of the current branch, None before its first model
+    combination: Option<Vec<bool>>,
+}
+
+// The state of a truth table enumeration. The first variable is the most significant bit of the
+// row number.
+struct Rows {
+    arena: PropositionArena,
+    root: PropId,
+    variables: Vec<(String, usize)>,
+    projected: Vec<bool>,
+    values: Vec<bool>,
+    row: u64,
+    // The projections of the models found so far, if some variables are projected away
+    seen: Option<HashSet<Vec<bool>>>,
+}
+
+impl<'a> Models<'a> {
+    /// Creates an enumeration of the models of a proposition with the given decider.
+    pub fn new(decider: &'a dyn Decider, proposition: &Proposition) -> Self {
+        Self::with_source(Source::Blocking {
+            decider,
+            proposition: proposition.clone(),
+            blocking: None,
+        })
+    }
+
+    pub(crate) fn incremental(proposition: &Proposition) -> Self {
+        Self::with_source(Source::Incremental {
+            proposition: proposition.clone(),
+            search: None,
+        })
+    }
+
+    pub(crate) fn branches(prover: &'a Prover, proposition: &Proposition) -> Self {
+        Self::with_source(Source::Branches {
+            prover,
+            proposition: proposition.clone(),
+            branches: None,
+        })
+    }
+
+    pub(crate) fn truth_table(proposition: &Proposition) -> Self {
+        Self::with_source(Source::TruthTable {
+            proposition: proposition.clone(),
+            rows: None,
+        })
+    }
+
+    fn with_source(source: Source<'a>) -> Self {
+        Self {
+            source,
+            projection: None,
+            limit: None,
+            found: 0,
+            finished: false,
+        }
+    }
+
+    /// Projects the models onto the given variables.
+    pub fn project<S: Into<String>>(mut self, variables: impl IntoIterator<Item = S>) -> Self {
+        self.projection = Some(variables.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Stops the enumeration after the given number of models.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn next_model(&mut self) -> Result<Option<Assignment>> {
+        // Without a projection the models assign the variables of the proposition.
+        let projection = match &self.source {
+            Source::Blocking { proposition, .. }
+            | Source::Incremental { proposition, .. }
+            | Source::Branches { proposition, .. }
+            | Source::TruthTable { proposition, .. } => self
+                .projection
+                .get_or_insert_with(|| proposition.get_variables()),
+        };
+        match &mut self.source {
+            Source::Blocking {
+                decider,
+                proposition,
+                blocking,
+            } => {
+                if blocking.is_none() {
+                    *blocking = Some(Blocking::new(proposition, projection)?);
+                }
+                let blocking = blocking.as_mut().expect("The clauses are encoded");
+                let Some(model) =
+                    decider.find_model_with_clauses(&blocking.proposition, &blocking.clause_set)?
+                else {
+                    return Ok(None);
+                };
+                match blocking.clause(&model) {
+                    Some(clause) => blocking.block(clause),
+                    None => self.finished = true,
+                }
+                Ok(Some(blocking.model(&model)))
+            }
+            Source::Incremental {
+                proposition,
+                search,
+            } => {
+                if search.is_none() {
+                    let blocking = Blocking::new(proposition, projection)?;
+                    let started = Search::new(&blocking.clause_set);
+                    *search = Some((blocking, started));
+                }
+                let (blocking, search) = search.as_mut().expect("The search is started");
+                let Some(values) = search.as_mut().and_then(Search::run) else {
+                    return Ok(None);
+                };
+                // The search keeps the blocking clauses, the blocking state only the projection.
+                let model = blocking.clause_set.assignment(&values);
+                match blocking.clause(&model) {
+                    Some(clause) => {
+                        if !search.as_mut().is_some_and(|search| search.add(&clause)) {
+                            *search = None;
+                        }
+                    }
+                    None => self.finished = true,
+                }
+                Ok(Some(blocking.model(&model)))
+            }
+            Source::Branches {
+                prover,
+                proposition,
+                branches,
+            } => {
+                if branches.is_none() {
+                    *branches = Some(Branches {
+                        variables: projection.iter().cloned().collect(),
+                        branches: prover.open_branches(proposition)?,
+                        branch: 0,
+                        combination: None,
+                    });
+                }
+                Ok(branches.as_mut().and_then(Branches::next_model))
+            }
+            Source::TruthTable { proposition, rows } => {
+                if rows.is_none() {
+                    *rows = Some(Rows::new(proposition, projection)?);
+                }
+                Ok(rows.as_mut().and_then(Rows::next_model))
+            }
+        }
+    }
+}
+
+impl Iterator for Models<'_> {
+    type Item = Result<Assignment>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished || self.limit.is_some_and(|limit| self.found >= limit) {
+            return None;
+        }
+        match self.next_model() {
+            Ok(Some(model)) => {
+                self.found += 1;
+                Some(Ok(model))
+            }
+            Ok(None) => {
+                self.finished = true;
+                None
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl Blocking {
+    fn new(proposition: &Proposition, projection: &BTreeSet<String>) -> Result<Self> {
+        let mut clause_set = ClauseSet::tseitin(proposition)?;
+        // Projected variables that don't occur in the proposition become named variables, the
+        // auxiliary variables are moved behind them.
+        let missing = projection
+            .iter()
+            .filter(|name| !clause_set.variables.contains(name))
+            .cloned()
+            .collect::<Vec<_>>();
+        let named = clause_set.variables.len();
+        for literal in clause_set.clauses.iter_mut().flatten() {
+            if literal.variable >= named {
+                literal.variable += missing.len();
+            }
+        }
+        clause_set.variables.extend(missing);
+        clause_set.variable_count = clause_set.variables.len() + clause_set.variable_count - named;
+        let variables = projection
+            .iter()
+            .map(|name| {
+                let index = clause_set
+                    .variables
+                    .iter()
+                    .position(|v| v == name)
+                    .expect("Each projected variable is named");
+                (name.clone(), index)
+            })
+            .collect();
+        Ok(Self {
+            proposition: proposition.clone(),
+            clause_set,
+            variables,
+        })
+    }
+
+    // Variables without a value are free, FALSE is as good as TRUE.
+    fn model(&self, model: &Assignment) -> Assignment {
+        self.variables
+            .iter()
+            .map(|(name, _)| (name.clone(), model.get(name).unwrap_or(false)))
+            .collect()
+    }
+
+    // Returns the clause that excludes the projection of the model, None if nothing is projected.
+    fn clause(&self, model: &Assignment) -> Option<Vec<Literal>> {
+        let clause = self
+            .variables
+            .iter()
+            .map(|(name, index)| Literal::new(*index, !model.get(name).unwrap_or(false)))
+            .collect::<Vec<_>>();
+        (!clause.is_empty()).then_some(clause)
+    }
+
+    // Adds the clause to the clauses and to the proposition.
+    fn block(&mut self, clause: Vec<Literal>) {
+        let disjunction = clause
+            .iter()
+            .map(|literal| {
+                let atom = Proposition::Atom(self.clause_set.variables[literal.variable].clone());
+                if literal.positive {
+                    atom
+                } else {
+                    Proposition::Negation(Negation {
+                        inner: Box::new(atom),
+                    })
+                }
+            })
+            .reduce(|left, right| {
+                Proposition::Disjunction(Disjunction {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                })
+            })
+            .expect("A blocking clause has a literal");
+        let proposition = std::mem::replace(&mut self.proposition, Proposition::Void);
+        self.proposition = Proposition::Conjunction(Conjunction {
+            left: Box::new(proposition),
+            right: Box::new(disjunction),
+        });
+        self.clause_set.clauses.push(clause);
+    }
+}
+
+impl Branches {
+    fn next_model(&mut self) -> Option<Assignment> {
+        while let Some(literals) = self.branches.get(self.branch) {
+            let combination = match &mut self.combination {
+                Some(combination) => {
+                    if !advance(combination) {
+                        // All combinations of the free variables have been tried.
+                        self.branch += 1;
+                        self.combination = None;
+                        continue;
+                    }
+                    combination
+                }
+                None => {
+                    let free = self
+                        .variables
+                        .iter()
+                        .filter(|v| !literals.contains_key(*v))
+                        .count();
+                    self.combination.insert(vec![false; free])
+                }
+            };
+            let mut free_values = combination.iter();
+            let values = self
+                .variables
+                .iter()
+                .map(|v| match literals.get(v) {
+                    Some(value) => *value,
+                    None => *free_values.next().expect("A value per free variable"),
+                })
+                .collect::<Vec<_>>();
+            let admitted = |literals: &BTreeMap<String, bool>| {
+                self.variables
+                    .iter()
+                    .zip(&values)
+                    .all(|(v, value)| literals.get(v).is_none_or(|literal| literal == value))
+            };
+            if !self.branches[..self.branch].iter().any(admitted) {
+                return Some(self.variables.iter().cloned().zip(values).collect());
+            }
+        }
+        None
+    }
+}
+
+// Advances the values to the next combination in binary counting order, returns false after the
+// last one.
+fn advance(values: &mut [bool]) -> bool {
+    let Some(last_false) = values.iter().rposition(|value| !value) else {
+        return false;
+    };
+    values[last_false] = true;
+    values[last_false + 1..].fill(false);
+    true
+}
+
+impl Rows {
+    fn new(proposition: &Proposition, projection: &BTreeSet<String>) -> Result<Self> {
+        let mut names = proposition.get_variables();
+        names.extend(projection.iter().cloned());
+        TableGenerator::validate_variable_count(names.len())?;
+        let mut arena = PropositionArena::new();
+        let root = arena.intern(proposition)?;
+        let variables = names
+            .into_iter()
+            .map(|name| {
+                // Projected variables may be missing in the proposition.
+                let index = arena.variable_index(&name).unwrap_or_else(|| {
+                    arena.atom(&name);
+                    arena.variables().len() - 1
+                });
+                (name, index)
+            })
+            .collect::<Vec<_>>();
+        let projected = variables
+            .iter()
+            .map(|(name, _)| projection.contains(name))
+            .collect::<Vec<_>>();
+        Ok(Self {
+            seen: projected.contains(&false).then(HashSet::new),
+            projected,
+            values: vec![false; arena.variables().len()],
+            arena,
+            root,
+            variables,
+            row: 0,
+        })
+    }
+
+    fn next_model(&mut self) -> Option<Assignment> {
+        let count = self.variables.len();
+        while self.row < 1 << count {
+            let row = self.row;
+            self.row += 1;
+            for (i, (_, index)) in self.variables.iter().enumerate() {
+                self.values[*index] = row & (1 << (count - 1 - i)) != 0;
+            }
+            if !self.arena.evaluate(self.root, &self.values) {
+                continue;
+            }
+            let model = self
+                .variables
+                .iter()
+                .zip(&self.projected)
+                .filter(|(_, projected)| **projected)
+                .map(|((name, index), _)| (name, self.values[*index]));
+            if let Some(seen) = &mut self.seen
+                && !seen.insert(model.clone().map(|(_, value)| value).collect())
+            {
+                continue;
+            }
+            return Some(model.map(|(name, value)| (name.clone(), value)).collect());
+        }
+        None
+    }
+}
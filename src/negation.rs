@@ -1,7 +1,7 @@
 use crate::proposition::Proposition;
 use std::fmt::{Display, Error, Formatter};
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 /// Negation struct represents an unary logical negation operation.
 pub struct Negation {
     pub inner: Box<Proposition>,
@@ -0,0 +1,200 @@
+use std::fmt::{Display, Error, Formatter};
+
+use crate::{argument::Argument, proposition::Proposition};
+
+/// The notation in which propositions and arguments are written
+///
+/// The parser accepts all notations, even mixed in one proposition. The notation only selects
+/// the symbols that are used for the output. Connectives without an alternative spelling are
+/// written like in the [`Ascii`](Notation::Ascii) notation.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{notation::Notation, proposition::Proposition, negation::Negation};
+///
+/// let proposition = Proposition::Negation(Negation {
+///     inner: Box::new("p".into()),
+/// });
+/// assert_eq!(proposition.with_notation(Notation::Unicode).to_string(), "¬p");
+/// assert_eq!(proposition.with_notation(Notation::Words).to_string(), "not p");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// `!`, `&`, `|`, `->`, `<->`, `^`, `NAND`, `NOR`, `T`, `F` and `|-`
+    #[default]
+    Ascii,
+    /// `~`, `/\`, `\/`, `=>` and `<=>`
+    Slashes,
+    /// `¬`, `∧`, `∨`, `→`, `↔`, `⊕`, `↑`, `↓`, `⊤`, `⊥` and `⊢`
+    Unicode,
+    /// `not`, `and`, `or`, `xor`, `nand`, `nor`, `true` and `false`
+    Words,
+}
+
+impl Notation {
+    /// Returns the prefix of a negation, including a separating space if needed.
+    pub fn negation(self) -> &'static str {
+        match self {
+            Notation::Ascii => "!",
+            Notation::Slashes => "~",
+            Notation::Unicode => "¬",
+            Notation::Words => "not ",
+        }
+    }
+
+    /// Returns the operator of a conjunction.
+    pub fn conjunction(self) -> &'static str {
+        match self {
+            Notation::Ascii => "&",
+            Notation::Slashes => "/\\",
+            Notation::Unicode => "∧",
+            Notation::Words => "and",
+        }
+    }
+
+    /// Returns the operator of a disjunction.
+    pub fn disjunction(self) -> &'static str {
+        match self {
+            Notation::Ascii => "|",
+            Notation::Slashes => "\\/",
+            Notation::Unicode => "∨",
+            Notation::Words => "or",
+        }
+    }
+
+    /// Returns the operator of an implication.
+    pub fn implication(self) -> &'static str {
+        match self {
+            Notation::Ascii | Notation::Words => "->",
+            Notation::Slashes => "=>",
+            Notation::Unicode => "→",
+        }
+    }
+
+    /// Returns the operator of a bi-implication.
+    pub fn bi_implication(self) -> &'static str {
+        match self {
+            Notation::Ascii | Notation::Words => "<->",
+            Notation::Slashes => "<=>",
+            Notation::Unicode => "↔",
+        }
+    }
+
+    /// Returns the operator of an exclusive disjunction.
+    pub fn exclusive_disjunction(self) -> &'static str {
+        match self {
+            Notation::Unicode => "⊕",
+            Notation::Words => "xor",
+            _ => "^",
+        }
+    }
+
+    /// Returns the operator of an alternative denial (NAND).
+    pub fn alternative_denial(self) -> &'static str {
+        match self {
+            Notation::Unicode => "↑",
+            Notation::Words => "nand",
+            _ => "NAND",
+        }
+    }
+
+    /// Returns the operator of a joint denial (NOR).
+    pub fn joint_denial(self) -> &'static str {
+        match self {
+            Notation::Unicode => "↓",
+            Notation::Words => "nor",
+            _ => "NOR",
+        }
+    }
+
+    /// Returns the symbol of the given boolean constant.
+    pub fn constant(self, value: bool) -> &'static str {
+        match (self, value) {
+            (Notation::Unicode, true) => "⊤",
+            (Notation::Unicode, false) => "⊥",
+            (Notation::Words, true) => "true",
+            (Notation::Words, false) => "false",
+            (_, true) => "T",
+            (_, false) => "F",
+        }
+    }
+
+    /// Returns the turnstile that separates the premises of an argument from its conclusion.
+    pub fn turnstile(self) -> &'static str {
+        match self {
+            Notation::Unicode => "⊢",
+            _ => "|-",
+        }
+    }
+}
+
+/// A proposition or an argument that is displayed in a given [`Notation`]
+///
+/// It is created by [`Proposition::with_notation`] or [`Argument::with_notation`]. Like the
+/// [`Display`] implementations of these types it sets all binary operations in parentheses, so the
+/// output can be parsed again.
+#[derive(Clone, Copy, Debug)]
+pub struct Notated<'a, T> {
+    pub value: &'a T,
+    pub notation: Notation,
+}
+
+impl Display for Notated<'_, Proposition> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        let notation = self.notation;
+        let binary = |f: &mut Formatter<'_>, left: &Proposition, op, right: &Proposition| {
+            write!(
+                f,
+                "({} {op} {})",
+                left.with_notation(notation),
+                right.with_notation(notation)
+            )
+        };
+        match self.value {
+            Proposition::Void => write!(f, "()"),
+            Proposition::Constant(value) => write!(f, "{}", notation.constant(*value)),
+            Proposition::Atom(v) => write!(f, "{}", v),
+            Proposition::Negation(n) => write!(
+                f,
+                "{}{}",
+                notation.negation(),
+                n.inner.with_notation(notation)
+            ),
+            Proposition::Implication(i) => binary(f, &i.left, notation.implication(), &i.right),
+            Proposition::BiImplication(b) => {
+                binary(f, &b.left, notation.bi_implication(), &b.right)
+            }
+            Proposition::Disjunction(d) => binary(f, &d.left, notation.disjunction(), &d.right),
+            Proposition::Conjunction(c) => binary(f, &c.left, notation.conjunction(), &c.right),
+            Proposition::ExclusiveDisjunction(x) => {
+                binary(f, &x.left, notation.exclusive_disjunction(), &x.right)
+            }
+            Proposition::AlternativeDenial(a) => {
+                binary(f, &a.left, notation.alternative_denial(), &a.right)
+            }
+            Proposition::JointDenial(j) => binary(f, &j.left, notation.joint_denial(), &j.right),
+        }
+    }
+}
+
+impl Display for Notated<'_, Argument> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        let notation = self.notation;
+        for (i, premise) in self.value.premises.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", premise.with_notation(notation))?;
+        }
+        if !self.value.premises.is_empty() {
+            write!(f, " ")?;
+        }
+        write!(
+            f,
+            "{} {}",
+            notation.turnstile(),
+            self.value.conclusion.with_notation(notation)
+        )
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{notation::Notation, proposition::Proposition};
+
+/// A printer that writes propositions with as few parentheses as the grammar allows
+///
+/// The [`Display`](std::fmt::Display) implementation of [`Proposition`] sets every binary
+/// operation in parentheses. The pretty printer omits the parentheses that the precedence and the
+/// associativity of the operators make redundant, e.g. `((p & q) -> p)` is printed as
+/// `p & q -> p`. Nested implications keep their parentheses, so the output is parsed into the
+/// same proposition again with either [`Associativity`](crate::raa_tt_grammar::Associativity) of
+/// implication chains.
+///
+/// If a `width` is given, propositions that don't fit into a line are broken before their
+/// operators. The operands of each line are indented by `indent` spaces per level of
+/// parentheses. Line breaks are whitespace for the parser, so the round trip still holds.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{
+///     conjunction::Conjunction, implication::Implication, pretty::PrettyPrinter,
+///     proposition::Proposition,
+/// };
+///
+/// let proposition = Proposition::Implication(Implication {
+///     left: Box::new(Proposition::Conjunction(Conjunction {
+///         left: Box::new("p".into()),
+///         right: Box::new("q".into()),
+///     })),
+///     right: Box::new("p".into()),
+/// });
+/// assert_eq!(proposition.to_string(), "((p & q) -> p)");
+/// assert_eq!(PrettyPrinter::new().print(&proposition), "p & q -> p");
+///
+/// let printer = PrettyPrinter {
+///     width: Some(8),
+///     ..Default::default()
+/// };
+/// assert_eq!(printer.print(&proposition), "p & q\n-> p");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrettyPrinter {
+    /// The symbols of the operators
+    pub notation: Notation,
+
+    /// The maximum length of a line, unlimited if None
+    pub width: Option<usize>,
+
+    /// The number of spaces per level of indentation
+    pub indent: usize,
+}
+
+impl Default for PrettyPrinter {
+    fn default() -> Self {
+        Self {
+            notation: Notation::default(),
+            width: None,
+            indent: 4,
+        }
+    }
+}
+
+// A line of the output with its level of indentation
+type Line = (usize, String);
+
+impl PrettyPrinter {
+    /// Creates a printer that writes a proposition into a single line in ASCII notation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the proposition with minimal parentheses, broken into lines if a width is given.
+    ///
+    /// A line is only longer than the width if a single operand doesn't fit into it.
+    pub fn print(&self, proposition: &Proposition) -> String {
+        self.layout(proposition, 0, 0)
+            .into_iter()
+            .map(|(depth, text)| format!("{}{text}", " ".repeat(depth * self.indent)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // Writes the proposition into a single line.
+    fn flat(&self, proposition: &Proposition) -> String {
+        if let Some((left, op, right)) = self.operands(proposition) {
+            let (left_parens, right_parens) = self.parens(proposition, left, right);
+            format!(
+                "{} {op} {}",
+                self.flat_operand(left, left_parens),
+                self.flat_operand(right, right_parens)
+            )
+        } else if let Proposition::Negation(n) = proposition {
+            format!(
+                "{}{}",
+                self.notation.negation(),
+                self.flat_operand(&n.inner, precedence(&n.inner) < NEGATION)
+            )
+        } else {
+            proposition.with_notation(self.notation).to_string()
+        }
+    }
+
+    fn flat_operand(&self, proposition: &Proposition, parens: bool) -> String {
+        if parens {
+            format!("({})", self.flat(proposition))
+        } else {
+            self.flat(proposition)
+        }
+    }
+
+    // Lays out the proposition at the given depth. The first line is preceded by the given
+    // number of characters.
+    fn layout(&self, proposition: &Proposition, depth: usize, prefix: usize) -> Vec<Line> {
+        let flat = self.flat(proposition);
+        if self.fits(depth, prefix, &flat) {
+            return vec![(depth, flat)];
+        }
+        if let Proposition::Negation(n) = proposition {
+            let negation = self.notation.negation();
+            let parens = precedence(&n.inner) < NEGATION;
+            let mut lines =
+                self.layout_operand(&n.inner, parens, depth, prefix + negation.chars().count());
+            lines[0].1.insert_str(0, negation);
+            return lines;
+        }
+        if self.operands(proposition).is_none() {
+            return vec![(depth, flat)];
+        }
+        // A chain of left associative operators of the same precedence is broken before each
+        // operator. The chain ends at an operand in parentheses.
+        let level = precedence(proposition);
+        let mut operands = Vec::new();
+        let mut operators = Vec::new();
+        let mut rest = (proposition, false);
+        while let (chain, false) = rest
+            && precedence(chain) == level
+            && let Some((left, op, right)) = self.operands(chain)
+        {
+            let (left_parens, right_parens) = self.parens(chain, left, right);
+            operators.push(op);
+            operands.push((right, right_parens));
+            rest = (left, left_parens);
+        }
+        operands.push(rest);
+        operands.reverse();
+        operators.reverse();
+        let mut operands = operands.into_iter();
+        let (first, parens) = operands.next().expect("A chain has two operands at least");
+        let mut lines = self.layout_operand(first, parens, depth, prefix);
+        for (op, (operand, parens)) in operators.into_iter().zip(operands) {
+            let mut operand = self.layout_operand(operand, parens, depth, op.chars().count() + 1);
+            operand[0].1.insert_str(0, &format!("{op} "));
+            lines.append(&mut operand);
+        }
+        lines
+    }
+
+    // Lays out an operand. Parentheses that don't fit into a line get lines of their own and
+    // their content is indented.
+    fn layout_operand(
+        &self,
+        proposition: &Proposition,
+        parens: bool,
+        depth: usize,
+        prefix: usize,
+    ) -> Vec<Line> {
+        if !parens {
+            return self.layout(proposition, depth, prefix);
+        }
+        let flat = self.flat_operand(proposition, true);
+        if self.fits(depth, prefix, &flat) {
+            return vec![(depth, flat)];
+        }
+        let mut lines = vec![(depth, "(".to_owned())];
+        lines.append(&mut self.layout(proposition, depth + 1, 0));
+        lines.push((depth, ")".to_owned()));
+        lines
+    }
+
+    // Returns whether the operands of a binary operation need parentheses. An operand needs them
+    // if it binds weaker than the operation, or equally on the right side. Implications in
+    // implications always need them, because the grammar reads chains of them depending on its
+    // associativity and warns about them.
+    fn parens(
+        &self,
+        proposition: &Proposition,
+        left: &Proposition,
+        right: &Proposition,
+    ) -> (bool, bool) {
+        let level = precedence(proposition);
+        if let Proposition::Implication(_) = proposition {
+            (precedence(left) <= level, precedence(right) <= level)
+        } else {
+            (precedence(left) < level, precedence(right) <= level)
+        }
+    }
+
+    fn fits(&self, depth: usize, prefix: usize, text: &str) -> bool {
+        self.width
+            .is_none_or(|width| depth * self.indent + prefix + text.chars().count() <= width)
+    }
+
+    // Returns the operands and the operator of a binary operation.
+    fn operands<'a>(
+        &self,
+        proposition: &'a Proposition,
+    ) -> Option<(&'a Proposition, &'static str, &'a Proposition)> {
+        let notation = self.notation;
+        match proposition {
+            Proposition::Implication(i) => Some((&i.left, notation.implication(), &i.right)),
+            Proposition::BiImplication(b) => Some((&b.left, notation.bi_implication(), &b.right)),
+            Proposition::Disjunction(d) => Some((&d.left, notation.disjunction(), &d.right)),
+            Proposition::Conjunction(c) => Some((&c.left, notation.conjunction(), &c.right)),
+            Proposition::ExclusiveDisjunction(x) => {
+                Some((&x.left, notation.exclusive_disjunction(), &x.right))
+            }
+            Proposition::AlternativeDenial(a) => {
+                Some((&a.left, notation.alternative_denial(), &a.right))
+            }
+            Proposition::JointDenial(j) => Some((&j.left, notation.joint_denial(), &j.right)),
+            _ => None,
+        }
+    }
+}
+
+const NEGATION: u8 = 6;
+
+// Returns how strongly the top level operator binds, see the precedence rules in raa_tt.par.
+fn precedence(proposition: &Proposition) -> u8 {
+    match proposition {
+        Proposition::BiImplication(_) => 1,
+        Proposition::Implication(_) => 2,
+        Proposition::Disjunction(_) | Proposition::JointDenial(_) => 3,
+        Proposition::ExclusiveDisjunction(_) => 4,
+        Proposition::Conjunction(_) | Proposition::AlternativeDenial(_) => 5,
+        Proposition::Negation(_) => NEGATION,
+        _ => 7,
+    }
+}
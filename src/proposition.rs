@@ -4,22 +4,325 @@ use std::{
 };
 
 use crate::{
-    bi_implication::BiImplication, conjunction::Conjunction, disjunction::Disjunction,
-    implication::Implication, negation::Negation,
+    alternative_denial::AlternativeDenial,
+    argument::Statement,
+    bi_implication::BiImplication,
+    cnf::ClauseSet,
+    conjunction::Conjunction,
+    disjunction::Disjunction,
+    errors::{RaaError, Result},
+    exclusive_disjunction::ExclusiveDisjunction,
+    implication::Implication,
+    joint_denial::JointDenial,
+    negation::Negation,
+    notation::{Notated, Notation},
+    raa_tt_grammar::Associativity,
 };
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum Proposition {
     #[default]
     Void,
+    Constant(bool),
     Atom(String),
     Negation(Negation),
     Implication(Implication),
     BiImplication(BiImplication),
     Disjunction(Disjunction),
     Conjunction(Conjunction),
+    ExclusiveDisjunction(ExclusiveDisjunction),
+    AlternativeDenial(AlternativeDenial),
+    JointDenial(JointDenial),
 }
 impl Proposition {
+    /// Converts the proposition into an equivalent [`ClauseSet`].
+    ///
+    /// Negations are pushed inwards and disjunctions are distributed over conjunctions. The
+    /// result contains only the variables of the proposition, but its size can grow exponentially
+    /// with the size of the proposition. Tautological and duplicate clauses are removed, thus a
+    /// tautology yields the empty clause set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{proposition::Proposition, bi_implication::BiImplication};
+    ///
+    /// // p <-> q
+    /// let proposition = Proposition::BiImplication(BiImplication {
+    ///     left: Box::new("p".into()),
+    ///     right: Box::new("q".into()),
+    /// });
+    /// assert_eq!(proposition.to_cnf().unwrap().to_string(), "(!p | q) & (p | !q)");
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError::VoidExpression`] if the proposition contains void expressions.
+    pub fn to_cnf(&self) -> Result<ClauseSet> {
+        ClauseSet::distribute(self)
+    }
+
+    /// Converts the proposition into an equisatisfiable [`ClauseSet`] with the Tseitin
+    /// transformation.
+    ///
+    /// Each connective gets a fresh auxiliary variable that is equivalent to its sub-formula, so
+    /// the clause set grows linearly with the proposition. The clause set is satisfiable if and
+    /// only if the proposition is, and each of its models is a model of the proposition when
+    /// restricted to the original variables. This is the usual input format of SAT solvers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{proposition::Proposition, conjunction::Conjunction};
+    ///
+    /// // p & q
+    /// let proposition = Proposition::Conjunction(Conjunction {
+    ///     left: Box::new("p".into()),
+    ///     right: Box::new("q".into()),
+    /// });
+    /// assert_eq!(
+    ///     proposition.to_tseitin_cnf().unwrap().to_string(),
+    ///     "(!t1 | p) & (!t1 | q) & (t1 | !p | !q) & (t1)"
+    /// );
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError::VoidExpression`] if the proposition contains void expressions.
+    pub fn to_tseitin_cnf(&self) -> Result<ClauseSet> {
+        ClauseSet::tseitin(self)
+    }
+
+    /// Returns the number of assignments of the variables of the proposition that make it TRUE.
+    ///
+    /// The models are counted on the [Tseitin encoding](Proposition::to_tseitin_cnf) with
+    /// [`ClauseSet::count_models`]. Neither the truth table nor the models are built, so the
+    /// number of variables is not limited to 16 like for the [`TableGenerator`].
+    ///
+    /// [`TableGenerator`]: crate::table_generator::TableGenerator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{proposition::Proposition, disjunction::Disjunction};
+    ///
+    /// // p | q
+    /// let proposition = Proposition::Disjunction(Disjunction {
+    ///     left: Box::new("p".into()),
+    ///     right: Box::new("q".into()),
+    /// });
+    /// assert_eq!(proposition.count_models().unwrap(), 3);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError::VoidExpression`] if the proposition contains void expressions and
+    /// [`RaaError::TooManyModels`] if the number of models exceeds [`u128::MAX`].
+    pub fn count_models(&self) -> Result<u128> {
+        self.to_tseitin_cnf()?.count_models()
+    }
+
+    /// Returns a wrapper that displays the proposition in the given notation.
+    ///
+    /// The [`Display`] implementation of the proposition itself uses [`Notation::Ascii`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{notation::Notation, proposition::Proposition, disjunction::Disjunction};
+    ///
+    /// let proposition = Proposition::Disjunction(Disjunction {
+    ///     left: Box::new("p".into()),
+    ///     right: Box::new(Proposition::Constant(false)),
+    /// });
+    /// assert_eq!(proposition.with_notation(Notation::Unicode).to_string(), "(p ∨ ⊥)");
+    /// assert_eq!(proposition.with_notation(Notation::Slashes).to_string(), "(p \\/ F)");
+    /// assert_eq!(proposition.with_notation(Notation::Words).to_string(), "(p or false)");
+    /// ```
+    pub fn with_notation(&self, notation: Notation) -> Notated<'_, Proposition> {
+        Notated {
+            value: self,
+            notation,
+        }
+    }
+
+    /// Converts the proposition into an equivalent proposition in negation normal form.
+    ///
+    /// Implications and bi-implications are eliminated and negations are pushed inwards until
+    /// they apply to atoms only. The rewrite rules are the ones of the truth tree rules, e.g.
+    /// `A <-> B` becomes `(A & B) | (!A & !B)`, so the result grows exponentially with nested
+    /// bi-implications only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{proposition::Proposition, implication::Implication, negation::Negation};
+    ///
+    /// // !(p -> !q)
+    /// let proposition = Proposition::Negation(Negation {
+    ///     inner: Box::new(Proposition::Implication(Implication {
+    ///         left: Box::new("p".into()),
+    ///         right: Box::new(Proposition::Negation(Negation {
+    ///             inner: Box::new("q".into()),
+    ///         })),
+    ///     })),
+    /// });
+    /// assert_eq!(proposition.to_nnf().unwrap().to_string(), "(p & q)");
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError::VoidExpression`] if the proposition contains void expressions.
+    pub fn to_nnf(&self) -> Result<Proposition> {
+        self.inner_to_nnf(true)
+    }
+
+    /// Converts the proposition into an equivalent proposition in disjunctive normal form.
+    ///
+    /// The result is a disjunction of conjunctions of literals. It is derived from the
+    /// [conjunctive normal form](Proposition::to_cnf) of the negated proposition, hence
+    /// contradictory and duplicate conjunctions are removed and its size can grow exponentially
+    /// with the size of the proposition. A contradiction is converted into the constant `F`, a
+    /// proposition whose negation contains the empty clause into the constant `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{proposition::Proposition, conjunction::Conjunction, disjunction::Disjunction};
+    ///
+    /// // p & (q | r)
+    /// let proposition = Proposition::Conjunction(Conjunction {
+    ///     left: Box::new("p".into()),
+    ///     right: Box::new(Proposition::Disjunction(Disjunction {
+    ///         left: Box::new("q".into()),
+    ///         right: Box::new("r".into()),
+    ///     })),
+    /// });
+    /// assert_eq!(proposition.to_dnf().unwrap().to_string(), "((p & q) | (p & r))");
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError::VoidExpression`] if the proposition contains void expressions.
+    pub fn to_dnf(&self) -> Result<Proposition> {
+        let negation = Proposition::Negation(Negation {
+            inner: Box::new(self.clone()),
+        });
+        let cnf = negation.to_cnf()?;
+        let literal = |variable: usize, positive: bool| {
+            let atom = Proposition::Atom(cnf.variables[variable].clone());
+            if positive {
+                atom
+            } else {
+                Proposition::Negation(Negation {
+                    inner: Box::new(atom),
+                })
+            }
+        };
+        // Each clause of the negation is negated into a conjunction of the complementary literals.
+        let terms = cnf.clauses.iter().map(|clause| {
+            clause
+                .iter()
+                .map(|l| literal(l.variable, !l.positive))
+                .reduce(|left, right| {
+                    Proposition::Conjunction(Conjunction {
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    })
+                })
+                .unwrap_or(Proposition::Constant(true))
+        });
+        let dnf = terms.reduce(|left, right| {
+            Proposition::Disjunction(Disjunction {
+                left: Box::new(left),
+                right: Box::new(right),
+            })
+        });
+        Ok(dnf.unwrap_or(Proposition::Constant(false)))
+    }
+
+    fn inner_to_nnf(&self, positive: bool) -> Result<Proposition> {
+        let conjunction = |left: Proposition, right: Proposition| {
+            Proposition::Conjunction(Conjunction {
+                left: Box::new(left),
+                right: Box::new(right),
+            })
+        };
+        let disjunction = |left: Proposition, right: Proposition| {
+            Proposition::Disjunction(Disjunction {
+                left: Box::new(left),
+                right: Box::new(right),
+            })
+        };
+        let nnf = match self {
+            Proposition::Void => Err(RaaError::VoidExpression)?,
+            Proposition::Constant(value) => Proposition::Constant(*value == positive),
+            Proposition::Atom(_) if positive => self.clone(),
+            Proposition::Atom(_) => Proposition::Negation(Negation {
+                inner: Box::new(self.clone()),
+            }),
+            Proposition::Negation(Negation { inner }) => inner.inner_to_nnf(!positive)?,
+            Proposition::Conjunction(Conjunction { left, right }) => {
+                let (left, right) = (left.inner_to_nnf(positive)?, right.inner_to_nnf(positive)?);
+                if positive {
+                    conjunction(left, right)
+                } else {
+                    disjunction(left, right)
+                }
+            }
+            Proposition::Disjunction(Disjunction { left, right }) => {
+                let (left, right) = (left.inner_to_nnf(positive)?, right.inner_to_nnf(positive)?);
+                if positive {
+                    disjunction(left, right)
+                } else {
+                    conjunction(left, right)
+                }
+            }
+            Proposition::Implication(Implication { left, right }) => {
+                let right = right.inner_to_nnf(positive)?;
+                if positive {
+                    disjunction(left.inner_to_nnf(false)?, right)
+                } else {
+                    conjunction(left.inner_to_nnf(true)?, right)
+                }
+            }
+            Proposition::BiImplication(BiImplication { left, right }) => disjunction(
+                conjunction(left.inner_to_nnf(true)?, right.inner_to_nnf(positive)?),
+                conjunction(left.inner_to_nnf(false)?, right.inner_to_nnf(!positive)?),
+            ),
+            // A ^ B is !(A <-> B)
+            Proposition::ExclusiveDisjunction(ExclusiveDisjunction { left, right }) => disjunction(
+                conjunction(left.inner_to_nnf(true)?, right.inner_to_nnf(!positive)?),
+                conjunction(left.inner_to_nnf(false)?, right.inner_to_nnf(positive)?),
+            ),
+            // A NAND B is !(A & B)
+            Proposition::AlternativeDenial(AlternativeDenial { left, right }) => {
+                let (left, right) = (
+                    left.inner_to_nnf(!positive)?,
+                    right.inner_to_nnf(!positive)?,
+                );
+                if positive {
+                    disjunction(left, right)
+                } else {
+                    conjunction(left, right)
+                }
+            }
+            // A NOR B is !(A | B)
+            Proposition::JointDenial(JointDenial { left, right }) => {
+                let (left, right) = (
+                    left.inner_to_nnf(!positive)?,
+                    right.inner_to_nnf(!positive)?,
+                );
+                if positive {
+                    conjunction(left, right)
+                } else {
+                    disjunction(left, right)
+                }
+            }
+        };
+        Ok(nnf)
+    }
+
     pub(crate) fn get_variables(&self) -> BTreeSet<String> {
         let mut vars = BTreeSet::new();
         self.inner_get_variables(&mut vars);
@@ -28,7 +331,7 @@ impl Proposition {
 
     fn inner_get_variables(&self, vars: &mut BTreeSet<String>) {
         match self {
-            Proposition::Void => (),
+            Proposition::Void | Proposition::Constant(_) => (),
             Proposition::Atom(v) => {
                 vars.insert(v.clone());
             }
@@ -49,21 +352,25 @@ impl Proposition {
                 left.inner_get_variables(vars);
                 right.inner_get_variables(vars);
             }
+            Proposition::ExclusiveDisjunction(ExclusiveDisjunction { left, right }) => {
+                left.inner_get_variables(vars);
+                right.inner_get_variables(vars);
+            }
+            Proposition::AlternativeDenial(AlternativeDenial { left, right }) => {
+                left.inner_get_variables(vars);
+                right.inner_get_variables(vars);
+            }
+            Proposition::JointDenial(JointDenial { left, right }) => {
+                left.inner_get_variables(vars);
+                right.inner_get_variables(vars);
+            }
         }
     }
 }
 
 impl Display for Proposition {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
-        match self {
-            Proposition::Void => write!(f, "()"),
-            Proposition::Atom(v) => write!(f, "{}", v),
-            Proposition::Negation(e) => write!(f, "{}", e),
-            Proposition::Implication(i) => write!(f, "{}", i),
-            Proposition::BiImplication(b) => write!(f, "{}", b),
-            Proposition::Disjunction(d) => write!(f, "{}", d),
-            Proposition::Conjunction(c) => write!(f, "{}", c),
-        }
+        write!(f, "{}", self.with_notation(Notation::Ascii))
     }
 }
 
@@ -73,107 +380,165 @@ impl From<&str> for Proposition {
     }
 }
 
+// An argument is converted into the implication from its premises to its conclusion.
 impl From<&crate::raa_tt_grammar_trait::RaaTtList<'_>> for Proposition {
     fn from(value: &crate::raa_tt_grammar_trait::RaaTtList<'_>) -> Self {
-        (&value.biconditional).into()
+        match Statement::from(value) {
+            Statement::Proposition(proposition) => proposition,
+            Statement::Argument(argument) => argument.to_implication(),
+        }
     }
 }
 
+// Implication chains are read left associative.
 impl From<&crate::raa_tt_grammar_trait::Biconditional<'_>> for Proposition {
     fn from(value: &crate::raa_tt_grammar_trait::Biconditional<'_>) -> Self {
+        value.to_proposition(Associativity::Left)
+    }
+}
+
+// Conversion of the syntax tree into propositions. Chains of implications are folded with the
+// given associativity, all other operators are left associative.
+pub(crate) trait SyntaxTree {
+    fn to_proposition(&self, implication: Associativity) -> Proposition;
+}
+
+impl SyntaxTree for crate::raa_tt_grammar_trait::Biconditional<'_> {
+    fn to_proposition(&self, implication: Associativity) -> Proposition {
         let crate::raa_tt_grammar_trait::Biconditional {
             conditional,
             biconditional_list,
-        } = value;
-        if biconditional_list.is_empty() {
-            conditional.into()
-        } else {
-            biconditional_list
-                .iter()
-                .fold(conditional.into(), |left: Proposition, b| {
-                    Proposition::BiImplication(BiImplication {
-                        left: Box::new(left),
-                        right: Box::new((&b.conditional).into()),
-                    })
+        } = self;
+        biconditional_list.iter().fold(
+            conditional.to_proposition(implication),
+            |left: Proposition, b| {
+                Proposition::BiImplication(BiImplication {
+                    left: Box::new(left),
+                    right: Box::new(b.conditional.to_proposition(implication)),
                 })
-        }
+            },
+        )
     }
 }
 
-impl From<&crate::raa_tt_grammar_trait::Conditional<'_>> for Proposition {
-    fn from(value: &crate::raa_tt_grammar_trait::Conditional<'_>) -> Self {
+impl SyntaxTree for crate::raa_tt_grammar_trait::Conditional<'_> {
+    fn to_proposition(&self, implication: Associativity) -> Proposition {
         let crate::raa_tt_grammar_trait::Conditional {
             disjunction,
             conditional_list,
-        } = value;
-        if conditional_list.is_empty() {
-            disjunction.into()
-        } else {
-            conditional_list
-                .iter()
-                .fold(disjunction.into(), |left: Proposition, b| {
-                    Proposition::Implication(Implication {
-                        left: Box::new(left),
-                        right: Box::new((&b.disjunction).into()),
-                    })
-                })
+        } = self;
+        let operands = std::iter::once(disjunction)
+            .chain(conditional_list.iter().map(|c| &c.disjunction))
+            .map(|d| d.to_proposition(implication));
+        let to_implication = |left, right| {
+            Proposition::Implication(Implication {
+                left: Box::new(left),
+                right: Box::new(right),
+            })
+        };
+        match implication {
+            Associativity::Left => operands.reduce(to_implication),
+            Associativity::Right => operands
+                .rev()
+                .reduce(|right, left| to_implication(left, right)),
         }
+        .expect("A conditional has at least one operand")
     }
 }
 
-impl From<&crate::raa_tt_grammar_trait::Disjunction<'_>> for Proposition {
-    fn from(value: &crate::raa_tt_grammar_trait::Disjunction<'_>) -> Self {
+impl SyntaxTree for crate::raa_tt_grammar_trait::Disjunction<'_> {
+    fn to_proposition(&self, implication: Associativity) -> Proposition {
         let crate::raa_tt_grammar_trait::Disjunction {
-            conjunction,
+            exclusive_disjunction,
             disjunction_list,
-        } = value;
+        } = self;
         if disjunction_list.is_empty() {
-            conjunction.into()
+            exclusive_disjunction.to_proposition(implication)
         } else {
-            disjunction_list
-                .iter()
-                .fold(conjunction.into(), |left: Proposition, b| {
-                    Proposition::Disjunction(Disjunction {
+            disjunction_list.iter().fold(
+                exclusive_disjunction.to_proposition(implication),
+                |left: Proposition, b| {
+                    let left = Box::new(left);
+                    let right = Box::new(b.exclusive_disjunction.to_proposition(implication));
+                    match b.disjunction_op {
+                        crate::raa_tt_grammar_trait::DisjunctionOp::Or(_) => {
+                            Proposition::Disjunction(Disjunction { left, right })
+                        }
+                        crate::raa_tt_grammar_trait::DisjunctionOp::Nor(_) => {
+                            Proposition::JointDenial(JointDenial { left, right })
+                        }
+                    }
+                },
+            )
+        }
+    }
+}
+
+impl SyntaxTree for crate::raa_tt_grammar_trait::ExclusiveDisjunction<'_> {
+    fn to_proposition(&self, implication: Associativity) -> Proposition {
+        let crate::raa_tt_grammar_trait::ExclusiveDisjunction {
+            conjunction,
+            exclusive_disjunction_list,
+        } = self;
+        if exclusive_disjunction_list.is_empty() {
+            conjunction.to_proposition(implication)
+        } else {
+            exclusive_disjunction_list.iter().fold(
+                conjunction.to_proposition(implication),
+                |left: Proposition, b| {
+                    Proposition::ExclusiveDisjunction(ExclusiveDisjunction {
                         left: Box::new(left),
-                        right: Box::new((&b.conjunction).into()),
+                        right: Box::new(b.conjunction.to_proposition(implication)),
                     })
-                })
+                },
+            )
         }
     }
 }
 
-impl From<&crate::raa_tt_grammar_trait::Conjunction<'_>> for Proposition {
-    fn from(value: &crate::raa_tt_grammar_trait::Conjunction<'_>) -> Self {
+impl SyntaxTree for crate::raa_tt_grammar_trait::Conjunction<'_> {
+    fn to_proposition(&self, implication: Associativity) -> Proposition {
         let crate::raa_tt_grammar_trait::Conjunction {
             factor,
             conjunction_list,
-        } = value;
+        } = self;
         if conjunction_list.is_empty() {
-            factor.into()
+            factor.to_proposition(implication)
         } else {
-            conjunction_list
-                .iter()
-                .fold(factor.into(), |left: Proposition, b| {
-                    Proposition::Conjunction(Conjunction {
-                        left: Box::new(left),
-                        right: Box::new((&b.factor).into()),
-                    })
-                })
+            conjunction_list.iter().fold(
+                factor.to_proposition(implication),
+                |left: Proposition, b| {
+                    let left = Box::new(left);
+                    let right = Box::new(b.factor.to_proposition(implication));
+                    match b.conjunction_op {
+                        crate::raa_tt_grammar_trait::ConjunctionOp::And(_) => {
+                            Proposition::Conjunction(Conjunction { left, right })
+                        }
+                        crate::raa_tt_grammar_trait::ConjunctionOp::Nand(_) => {
+                            Proposition::AlternativeDenial(AlternativeDenial { left, right })
+                        }
+                    }
+                },
+            )
         }
     }
 }
 
-impl From<&crate::raa_tt_grammar_trait::Factor<'_>> for Proposition {
-    fn from(value: &crate::raa_tt_grammar_trait::Factor<'_>) -> Self {
-        match value {
+impl SyntaxTree for crate::raa_tt_grammar_trait::Factor<'_> {
+    fn to_proposition(&self, implication: Associativity) -> Proposition {
+        match self {
+            crate::raa_tt_grammar_trait::Factor::Constant(c) => Proposition::Constant(matches!(
+                c.constant,
+                crate::raa_tt_grammar_trait::Constant::True(_)
+            )),
             crate::raa_tt_grammar_trait::Factor::Var(crate::raa_tt_grammar_trait::FactorVar {
                 var,
             }) => Proposition::Atom(var.var.text().to_owned()),
             crate::raa_tt_grammar_trait::Factor::Negation(f) => Proposition::Negation(Negation {
-                inner: Box::new((&f.negation.factor).into()),
+                inner: Box::new(f.negation.factor.to_proposition(implication)),
             }),
             crate::raa_tt_grammar_trait::Factor::LParBiconditionalRPar(b) => {
-                (&*b.biconditional).into()
+                b.biconditional.to_proposition(implication)
             }
         }
     }
@@ -1,32 +1,41 @@
 use parol_runtime::log::{debug, trace};
 use petgraph::{
-    algo::all_simple_paths,
+    Direction,
     dot::{Config, Dot},
     graph::NodeIndex,
     prelude::DiGraph,
-    visit::DfsPostOrder,
 };
 use std::{
-    cell::RefCell,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     fmt::{Display, Error, Formatter},
+    time::Instant,
     vec,
 };
 
 use crate::{
-    bi_implication::BiImplication,
-    conjunction::Conjunction,
-    disjunction::Disjunction,
+    arena::{PropId, PropNode, PropositionArena},
+    argument::Validity,
+    assignment::Assignment,
+    config::ProverConfig,
     errors::{RaaError, Result},
-    implication::Implication,
-    negation::Negation,
+    models::Models,
     proposition::Proposition,
+    stats::ProofStats,
+    strategy::{Candidate, ExpansionStrategy},
+    tableau::{Rule, Tableau},
 };
 
+/// The processing state of a node in a truth tree
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum TransformationState {
+pub enum TransformationState {
+    /// The node has not been decomposed yet
     #[default]
     Unprocessed,
+
+    /// The node has been decomposed by applying a rule
     Transformed,
+
+    /// The node is the leaf of a branch that contains a contradiction
     Closed,
 }
 
@@ -42,7 +51,96 @@ impl Display for TransformationState {
 
 // The nodes of our proposition tree are propositions paired with a transformation state to indicate
 // whether a node has been processed already or whether the branch is closed.
-pub(crate) type PropositionTree = DiGraph<(Proposition, TransformationState), ()>;
+// Additionally each node records the rule that decomposed it and the node it was derived from.
+// Closed leaves record the pair of contradicting nodes on their branch.
+#[derive(Debug, Clone)]
+pub(crate) struct TreeNode {
+    pub(crate) proposition: PropId,
+    pub(crate) state: TransformationState,
+    pub(crate) rule: Option<Rule>,
+    pub(crate) derived_from: Option<NodeIndex>,
+    pub(crate) closed_by: Option<(NodeIndex, NodeIndex)>,
+}
+
+impl TreeNode {
+    fn new(proposition: PropId, derived_from: Option<NodeIndex>) -> Self {
+        Self {
+            proposition,
+            state: TransformationState::Unprocessed,
+            rule: None,
+            derived_from,
+            closed_by: None,
+        }
+    }
+}
+
+pub(crate) type PropositionTree = DiGraph<TreeNode, ()>;
+
+// An open branch of a truth tree under development.
+// Besides its leaf it records the nodes on the branch, the formulas found on them and the inner
+// formulas of the negations among them. The formulas are interned, hence a contradiction is
+// detected in constant time when a node is appended.
+// The number of unprocessed nodes tells whether the branch is completely developed.
+// A node with the constant FALSE or the negated constant TRUE closes the branch on its own, it is
+// paired with itself.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct OpenBranch {
+    leaf: NodeIndex,
+    pub(crate) nodes: HashSet<NodeIndex>,
+    formulas: HashMap<PropId, NodeIndex>,
+    negated_inners: HashMap<PropId, NodeIndex>,
+    unprocessed: usize,
+}
+
+impl OpenBranch {
+    // Appends a node to the branch.
+    // Returns the pair of contradicting nodes if the new node contradicts a node of the branch.
+    fn push(
+        &mut self,
+        node_id: NodeIndex,
+        proposition: PropId,
+        arena: &PropositionArena,
+    ) -> Option<(NodeIndex, NodeIndex)> {
+        self.leaf = node_id;
+        self.nodes.insert(node_id);
+        self.unprocessed += 1;
+        let mut closing_pair = is_falsum(proposition, arena)
+            .then_some((node_id, node_id))
+            .or_else(|| self.negated_inners.get(&proposition).map(|i| (*i, node_id)));
+        if let PropNode::Negation(inner) = arena.node(proposition) {
+            closing_pair =
+                closing_pair.or_else(|| self.formulas.get(&inner).map(|i| (*i, node_id)));
+            self.negated_inners.entry(inner).or_insert(node_id);
+        }
+        self.formulas.entry(proposition).or_insert(node_id);
+        closing_pair
+    }
+
+    // Returns true if the proposition contradicts a node of the branch.
+    pub(crate) fn contradicts(&self, proposition: PropId, arena: &PropositionArena) -> bool {
+        is_falsum(proposition, arena)
+            || self.negated_inners.contains_key(&proposition)
+            || matches!(arena.node(proposition), PropNode::Negation(inner) if self.formulas.contains_key(&inner))
+    }
+}
+
+// Returns true if the proposition is FALSE under every assignment without being decomposed.
+fn is_falsum(proposition: PropId, arena: &PropositionArena) -> bool {
+    match arena.node(proposition) {
+        PropNode::Constant(value) => !value,
+        PropNode::Negation(inner) => arena.node(inner) == PropNode::Constant(true),
+        _ => false,
+    }
+}
+
+// The result of applying a rule to a proposition: the propositions that are appended to the left
+// and to the right branch. Only branching rules have a right side.
+#[derive(Debug, Clone)]
+pub(crate) struct Expansion {
+    pub(crate) rule: Option<Rule>,
+    pub(crate) left: Vec<PropId>,
+    pub(crate) right: Vec<PropId>,
+}
 
 /// The outcome of the prover algorithm for a specific proposition
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -79,19 +177,96 @@ impl Display for ProveResult {
     }
 }
 
-#[derive(Debug)]
-pub struct Prover {
-    root: RefCell<NodeIndex>,
+/// The outcome of [`Prover::equivalent`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Equivalence {
+    /// Both propositions have the same truth value under every assignment
+    Equivalent,
+
+    /// The contained assignment makes one of the propositions TRUE and the other one FALSE
+    Different(Assignment),
 }
 
-impl Default for Prover {
-    fn default() -> Self {
-        Self {
-            root: RefCell::new(NodeIndex::end()),
+impl Display for Equivalence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match self {
+            Equivalence::Equivalent => write!(f, "Equivalent"),
+            Equivalence::Different(_) => write!(f, "Not Equivalent"),
         }
     }
 }
 
+/// The outcome of [`Prover::prove_with_witness`]
+///
+/// Besides the [`ProveResult`] it contains the variable assignments that were read off the open
+/// branches of the truth trees. They explain *why* a proposition is not a tautology or not a
+/// contradiction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Witness {
+    /// The classification of the proposition
+    pub result: ProveResult,
+
+    /// An assignment under which the proposition is FALSE.
+    /// It is `None` if the proposition is a tautology.
+    pub counterexample: Option<Assignment>,
+
+    /// An assignment under which the proposition is TRUE.
+    /// It is `None` if the proposition is a contradiction.
+    pub model: Option<Assignment>,
+
+    /// The work the prover has done
+    pub stats: ProofStats,
+}
+
+/// The outcome of [`Prover::prove_with_tableaux`]
+///
+/// It contains the truth trees that were built to decide the proposition. The first tableau
+/// always starts with the negated proposition. If it stays open and no model of the proposition
+/// could be derived from its open branch, a second tableau starting with the proposition itself
+/// follows.
+#[derive(Debug, Clone)]
+pub struct Proof {
+    /// The classification of the proposition
+    pub result: ProveResult,
+
+    /// The truth trees in the order they were built
+    pub tableaux: Vec<Tableau>,
+
+    /// The work the prover has done
+    pub stats: ProofStats,
+}
+
+impl Proof {
+    /// Returns the truth tree that decides the result.
+    ///
+    /// This is the closed tableau for tautologies and contradictions and the tableau of the
+    /// negated proposition for contingent propositions. Its open branches describe
+    /// counterexamples.
+    pub fn tableau(&self) -> &Tableau {
+        self.tableaux
+            .iter()
+            .find(|t| t.is_closed())
+            .unwrap_or(&self.tableaux[0])
+    }
+
+    /// Returns an assignment under which the proposition is FALSE.
+    /// It is `None` if the proposition is a tautology.
+    pub fn counterexample(&self) -> Option<Assignment> {
+        let tableau = &self.tableaux[0];
+        tableau
+            .open_branches()
+            .next()
+            .map(|b| tableau.assignment(b))
+    }
+}
+
+// The propositions of the truth trees are interned in an arena that lives as long as a single
+// public call, so the prover keeps no state between proofs and can be shared between threads.
+#[derive(Debug, Default)]
+pub struct Prover {
+    config: ProverConfig,
+}
+
 impl Prover {
     /// Creates a new instance of the Prover.
     ///
@@ -109,6 +284,51 @@ impl Prover {
         Self::default()
     }
 
+    /// Creates a new instance of the Prover that decomposes the nodes of its truth trees in the
+    /// order given by the expansion strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{prover::Prover, strategy::ExpansionStrategy};
+    ///
+    /// let prover = Prover::with_strategy(ExpansionStrategy::AlphaFirst);
+    /// ```
+    pub fn with_strategy(strategy: ExpansionStrategy) -> Self {
+        Self::with_config(ProverConfig {
+            strategy,
+            ..Default::default()
+        })
+    }
+
+    /// Creates a new instance of the Prover with the given configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use raa_tt::{config::ProverConfig, prover::Prover};
+    ///
+    /// let config = ProverConfig {
+    ///     max_nodes: Some(10_000),
+    ///     ..Default::default()
+    /// };
+    /// let prover = Prover::with_config(config.with_timeout(Duration::from_secs(1)));
+    /// ```
+    pub fn with_config(config: ProverConfig) -> Self {
+        Self { config }
+    }
+
+    /// Returns the expansion strategy of the prover.
+    pub fn strategy(&self) -> &ExpansionStrategy {
+        &self.config.strategy
+    }
+
+    /// Returns the configuration of the prover.
+    pub fn config(&self) -> &ProverConfig {
+        &self.config
+    }
+
     /// Proves the logical validity of a proposition using the analytic tableaux (truth tree) method.
     ///
     /// This method implements a systematic proof technique that attempts to determine whether a
@@ -130,6 +350,9 @@ impl Prover {
     /// - **Disjunction (A ∨ B)**: Create two branches, one with A and one with B
     /// - **Implication (A → B)**: Create two branches, one with ¬A and one with B
     /// - **Biimplication (A ↔ B)**: Create two branches, one with A∧B and one with ¬A∧¬B
+    /// - **Exclusive disjunction (A ⊕ B)**: Create two branches, one with A∧¬B and one with ¬A∧B
+    /// - **Alternative denial (A ↑ B)**: Create two branches, one with ¬A and one with ¬B
+    /// - **Joint denial (A ↓ B)**: Add both ¬A and ¬B to the current branch
     /// - **Negation**: Apply De Morgan's laws and double negation elimination
     ///
     /// ## Complexity Analysis
@@ -224,344 +447,1302 @@ impl Prover {
     /// ## Errors
     ///
     /// Returns [`RaaError`] if the proposition contains invalid or void expressions.
+    /// Returns [`RaaError::GaveUp`] if a limit of the [`ProverConfig`] is exceeded.
     ///
     /// [`ProveResult::Proven`]: crate::prover::ProveResult::Proven
     /// [`ProveResult::Falsified`]: crate::prover::ProveResult::Falsified
     /// [`ProveResult::Contingent`]: crate::prover::ProveResult::Contingent
     /// [`RaaError`]: crate::errors::RaaError
+    /// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+    /// [`ProverConfig`]: crate::config::ProverConfig
     pub fn prove(&self, proposition: &Proposition) -> Result<ProveResult> {
-        let mut prove_result = self.try_prove(proposition, true)?;
-        if prove_result == ProveResult::Contingent {
-            prove_result = self.try_prove(proposition, false)?;
-        }
-        Ok(prove_result)
+        let mut arena = PropositionArena::new();
+        let (witness, _) = self.decide(
+            &mut arena,
+            proposition,
+            false,
+            &mut Budget::new(&self.config),
+        )?;
+        Ok(witness.result)
     }
 
-    fn try_prove(&self, proposition: &Proposition, negated: bool) -> Result<ProveResult> {
-        let mut prove_result = ProveResult::Processing;
-        let mut graph = PropositionTree::new();
-        self.init_proposition_tree(proposition, &mut graph, negated)?;
-        while prove_result == ProveResult::Processing {
-            trace!(
-                "{}{:?}",
-                if negated { "neg " } else { "" },
-                Dot::with_attr_getters(
-                    &graph,
-                    &[Config::EdgeNoLabel, Config::NodeNoLabel],
-                    &|_, _| { String::default() },
-                    &|g, n| { format!("label = \"{} ({}, {})\"", g[n.0].0, n.0.index(), g[n.0].1) }
-                )
-            );
-            prove_result = self.inner_prove(&mut graph, negated)?;
+    /// Proves a proposition like [`Prover::prove`] and additionally returns the variable
+    /// assignments that witness the result.
+    ///
+    /// If the truth tree of the negated proposition stays open, each of its open branches describes
+    /// an assignment that makes the proposition false. The literals found on the branch determine
+    /// the values of their variables, all other variables of the proposition are set to false.
+    /// The model is either one of a few candidate assignments derived from the counterexample or
+    /// it is read off an open branch of the truth tree of the proposition itself.
+    ///
+    /// Every assignment satisfies a tautology, hence for [`ProveResult::Proven`] the model with all
+    /// variables set to false is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{
+    ///     prover::{Prover, ProveResult},
+    ///     proposition::Proposition,
+    ///     implication::Implication,
+    /// };
+    ///
+    /// // P → Q is false for P = T and Q = F
+    /// let proposition = Proposition::Implication(Implication {
+    ///     left: Box::new("P".into()),
+    ///     right: Box::new("Q".into()),
+    /// });
+    ///
+    /// let witness = Prover::new().prove_with_witness(&proposition).unwrap();
+    /// assert_eq!(witness.result, ProveResult::Contingent);
+    /// let counterexample = witness.counterexample.unwrap();
+    /// assert_eq!(counterexample.get("P"), Some(true));
+    /// assert_eq!(counterexample.get("Q"), Some(false));
+    /// assert!(witness.model.is_some());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if the proposition contains invalid or void expressions.
+    /// Returns [`RaaError::GaveUp`] if a limit of the [`ProverConfig`] is exceeded.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    /// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+    /// [`ProverConfig`]: crate::config::ProverConfig
+    pub fn prove_with_witness(&self, proposition: &Proposition) -> Result<Witness> {
+        let mut arena = PropositionArena::new();
+        let (witness, _) = self.decide(
+            &mut arena,
+            proposition,
+            false,
+            &mut Budget::new(&self.config),
+        )?;
+        Ok(witness)
+    }
+
+    /// Proves a proposition like [`Prover::prove`] and returns the complete truth trees that
+    /// were built on the way.
+    ///
+    /// In contrast to [`Prover::prove`] the trees are always developed completely, even if an
+    /// open branch has been found already.
+    ///
+    /// Each [`Tableau`] lists its nodes together with the rule that decomposed them, the node each
+    /// row was derived from and the branches with their closure information. This allows to render
+    /// or post-process proofs in external tools.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{
+    ///     prover::{Prover, ProveResult},
+    ///     proposition::Proposition,
+    ///     disjunction::Disjunction,
+    ///     negation::Negation,
+    /// };
+    ///
+    /// // P ∨ ¬P
+    /// let proposition = Proposition::Disjunction(Disjunction {
+    ///     left: Box::new("P".into()),
+    ///     right: Box::new(Proposition::Negation(Negation {
+    ///         inner: Box::new("P".into()),
+    ///     })),
+    /// });
+    ///
+    /// let proof = Prover::new().prove_with_tableaux(&proposition).unwrap();
+    /// assert_eq!(proof.result, ProveResult::Proven);
+    /// assert_eq!(proof.tableaux.len(), 1);
+    /// assert!(proof.tableaux[0].is_closed());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if the proposition contains invalid or void expressions.
+    /// Returns [`RaaError::GaveUp`] if a limit of the [`ProverConfig`] is exceeded.
+    ///
+    /// [`Tableau`]: crate::tableau::Tableau
+    /// [`RaaError`]: crate::errors::RaaError
+    /// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+    /// [`ProverConfig`]: crate::config::ProverConfig
+    pub fn prove_with_tableaux(&self, proposition: &Proposition) -> Result<Proof> {
+        let mut arena = PropositionArena::new();
+        let (witness, graphs) = self.decide(
+            &mut arena,
+            proposition,
+            true,
+            &mut Budget::new(&self.config),
+        )?;
+        Ok(Proof {
+            result: witness.result,
+            stats: witness.stats,
+            tableaux: graphs
+                .iter()
+                .enumerate()
+                .map(|(i, graph)| Tableau::from_tree(graph, &arena, i == 0))
+                .collect(),
+        })
+    }
+
+    /// Checks whether an argument is valid, i.e. whether the conclusion follows from the premises.
+    ///
+    /// The premises and the negated conclusion are put at the root of a single truth tree. If all
+    /// branches close, no assignment makes all premises true and the conclusion false, hence the
+    /// argument is valid. Otherwise an open branch yields such an assignment as counterexample.
+    ///
+    /// Without premises this is equivalent to proving that the conclusion is a tautology.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{
+    ///     argument::Validity,
+    ///     prover::Prover,
+    ///     proposition::Proposition,
+    ///     implication::Implication,
+    /// };
+    ///
+    /// // Modus ponens: P → Q, P ⊢ Q
+    /// let premises = [
+    ///     Proposition::Implication(Implication {
+    ///         left: Box::new("P".into()),
+    ///         right: Box::new("Q".into()),
+    ///     }),
+    ///     "P".into(),
+    /// ];
+    /// let prover = Prover::new();
+    /// assert_eq!(prover.entails(&premises, &"Q".into()).unwrap(), Validity::Valid);
+    ///
+    /// // Affirming the consequent: P → Q, Q ⊢ P
+    /// let premises = [premises[0].clone(), "Q".into()];
+    /// let Validity::Invalid(counterexample) = prover.entails(&premises, &"P".into()).unwrap()
+    /// else {
+    ///     panic!("Argument should be invalid");
+    /// };
+    /// assert_eq!(counterexample.get("P"), Some(false));
+    /// assert_eq!(counterexample.get("Q"), Some(true));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if a proposition contains invalid or void expressions.
+    /// Returns [`RaaError::GaveUp`] if a limit of the [`ProverConfig`] is exceeded.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    /// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+    /// [`ProverConfig`]: crate::config::ProverConfig
+    pub fn entails(&self, premises: &[Proposition], conclusion: &Proposition) -> Result<Validity> {
+        let mut arena = PropositionArena::new();
+        let (prove_result, graph) = self.try_entail(
+            &mut arena,
+            premises,
+            conclusion,
+            false,
+            &mut Budget::new(&self.config),
+        )?;
+        Ok(Self::validity(
+            &arena,
+            prove_result,
+            &graph,
+            premises,
+            conclusion,
+        ))
+    }
+
+    /// Checks whether an argument is valid like [`Prover::entails`] and returns the finished truth
+    /// tree and the statistics of the proof, too.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if a proposition contains invalid or void expressions.
+    /// Returns [`RaaError::GaveUp`] if a limit of the [`ProverConfig`] is exceeded.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    /// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+    /// [`ProverConfig`]: crate::config::ProverConfig
+    pub fn entails_with_tableau(
+        &self,
+        premises: &[Proposition],
+        conclusion: &Proposition,
+    ) -> Result<(Validity, Tableau, ProofStats)> {
+        let mut arena = PropositionArena::new();
+        let mut budget = Budget::new(&self.config);
+        let (prove_result, graph) =
+            self.try_entail(&mut arena, premises, conclusion, true, &mut budget)?;
+        Ok((
+            Self::validity(&arena, prove_result, &graph, premises, conclusion),
+            Tableau::from_tree(&graph, &arena, true),
+            budget.stats(),
+        ))
+    }
+
+    /// Checks whether an argument is valid like [`Prover::entails`] and returns the statistics of
+    /// the proof, too.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if a proposition contains invalid or void expressions.
+    /// Returns [`RaaError::GaveUp`] if a limit of the [`ProverConfig`] is exceeded.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    /// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+    /// [`ProverConfig`]: crate::config::ProverConfig
+    pub fn entails_with_stats(
+        &self,
+        premises: &[Proposition],
+        conclusion: &Proposition,
+    ) -> Result<(Validity, ProofStats)> {
+        let mut arena = PropositionArena::new();
+        let mut budget = Budget::new(&self.config);
+        let (prove_result, graph) =
+            self.try_entail(&mut arena, premises, conclusion, false, &mut budget)?;
+        Ok((
+            Self::validity(&arena, prove_result, &graph, premises, conclusion),
+            budget.stats(),
+        ))
+    }
+
+    /// Checks whether one proposition implies another one, i.e. whether the right proposition is
+    /// TRUE under every assignment that makes the left proposition TRUE.
+    ///
+    /// This is the argument `left ⊢ right`, see [`Prover::entails`]. If the implication doesn't
+    /// hold, the counterexample makes the left proposition TRUE and the right one FALSE.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{
+    ///     argument::Validity,
+    ///     prover::Prover,
+    ///     proposition::Proposition,
+    ///     conjunction::Conjunction,
+    /// };
+    ///
+    /// // P ∧ Q implies P, but P doesn't imply P ∧ Q
+    /// let conjunction = Proposition::Conjunction(Conjunction {
+    ///     left: Box::new("P".into()),
+    ///     right: Box::new("Q".into()),
+    /// });
+    ///
+    /// let prover = Prover::new();
+    /// assert_eq!(prover.implies(&conjunction, &"P".into()).unwrap(), Validity::Valid);
+    /// let Validity::Invalid(counterexample) = prover.implies(&"P".into(), &conjunction).unwrap()
+    /// else {
+    ///     panic!("P shouldn't imply P ∧ Q");
+    /// };
+    /// assert_eq!(counterexample.get("Q"), Some(false));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if a proposition contains invalid or void expressions.
+    /// Returns [`RaaError::GaveUp`] if a limit of the [`ProverConfig`] is exceeded.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    /// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+    /// [`ProverConfig`]: crate::config::ProverConfig
+    pub fn implies(&self, left: &Proposition, right: &Proposition) -> Result<Validity> {
+        self.entails(std::slice::from_ref(left), right)
+    }
+
+    /// Checks whether two propositions are logically equivalent, i.e. whether they have the same
+    /// truth value under every assignment.
+    ///
+    /// The propositions are equivalent if each of them [implies](Prover::implies) the other one.
+    /// Otherwise the distinguishing assignment of the first failed implication is returned. The
+    /// limits of the [`ProverConfig`] apply to both checks together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{
+    ///     prover::{Equivalence, Prover},
+    ///     proposition::Proposition,
+    ///     disjunction::Disjunction,
+    ///     implication::Implication,
+    ///     negation::Negation,
+    /// };
+    ///
+    /// // P → Q is equivalent to ¬P ∨ Q, but not to Q → P
+    /// let implication = Proposition::Implication(Implication {
+    ///     left: Box::new("P".into()),
+    ///     right: Box::new("Q".into()),
+    /// });
+    /// let disjunction = Proposition::Disjunction(Disjunction {
+    ///     left: Box::new(Proposition::Negation(Negation {
+    ///         inner: Box::new("P".into()),
+    ///     })),
+    ///     right: Box::new("Q".into()),
+    /// });
+    /// let converse = Proposition::Implication(Implication {
+    ///     left: Box::new("Q".into()),
+    ///     right: Box::new("P".into()),
+    /// });
+    ///
+    /// let prover = Prover::new();
+    /// assert_eq!(
+    ///     prover.equivalent(&implication, &disjunction).unwrap(),
+    ///     Equivalence::Equivalent
+    /// );
+    /// let Equivalence::Different(assignment) = prover.equivalent(&implication, &converse).unwrap()
+    /// else {
+    ///     panic!("P → Q and Q → P should differ");
+    /// };
+    /// assert_ne!(
+    ///     implication.evaluate(&assignment).unwrap(),
+    ///     converse.evaluate(&assignment).unwrap()
+    /// );
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if a proposition contains invalid or void expressions.
+    /// Returns [`RaaError::GaveUp`] if a limit of the [`ProverConfig`] is exceeded.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    /// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+    /// [`ProverConfig`]: crate::config::ProverConfig
+    pub fn equivalent(&self, left: &Proposition, right: &Proposition) -> Result<Equivalence> {
+        let mut arena = PropositionArena::new();
+        let mut budget = Budget::new(&self.config);
+        for (premise, conclusion) in [(left, right), (right, left)] {
+            let premises = std::slice::from_ref(premise);
+            let (prove_result, graph) =
+                self.try_entail(&mut arena, premises, conclusion, false, &mut budget)?;
+            if let Validity::Invalid(assignment) =
+                Self::validity(&arena, prove_result, &graph, premises, conclusion)
+            {
+                return Ok(Equivalence::Different(assignment));
+            }
         }
-        trace!(
-            "{}{:?}",
-            if negated { "neg " } else { "" },
-            Dot::with_attr_getters(
-                &graph,
-                &[Config::EdgeNoLabel, Config::NodeNoLabel],
-                &|_, _| { String::default() },
-                &|g, n| { format!("label = \"{} ({}, {})\"", g[n.0].0, n.0.index(), g[n.0].1) }
+        Ok(Equivalence::Equivalent)
+    }
+
+    fn validity(
+        arena: &PropositionArena,
+        prove_result: ProveResult,
+        graph: &PropositionTree,
+        premises: &[Proposition],
+        conclusion: &Proposition,
+    ) -> Validity {
+        if prove_result == ProveResult::Proven {
+            Validity::Valid
+        } else {
+            let variables = premises
+                .iter()
+                .chain(std::iter::once(conclusion))
+                .flat_map(Proposition::get_variables)
+                .collect();
+            // An open tree always contains an open branch.
+            Validity::Invalid(
+                Self::open_branch_assignment(arena, graph, variables).unwrap_or_default(),
             )
-        );
-        Ok(prove_result)
+        }
     }
 
-    // We insert a (possibly negated) variant of our proposition and try to refute it later.
-    fn init_proposition_tree(
+    /// Checks whether a proposition is satisfiable, i.e. whether it is TRUE under at least one
+    /// assignment.
+    ///
+    /// In contrast to [`Prover::prove`] only a single truth tree is built. It starts with the
+    /// proposition itself. If all branches close, the proposition is unsatisfiable and `None` is
+    /// returned. Otherwise a model is read off an open branch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{
+    ///     prover::Prover,
+    ///     proposition::Proposition,
+    ///     conjunction::Conjunction,
+    ///     negation::Negation,
+    /// };
+    ///
+    /// // P ∧ ¬Q is satisfied by P = T and Q = F
+    /// let proposition = Proposition::Conjunction(Conjunction {
+    ///     left: Box::new("P".into()),
+    ///     right: Box::new(Proposition::Negation(Negation {
+    ///         inner: Box::new("Q".into()),
+    ///     })),
+    /// });
+    ///
+    /// let model = Prover::new().is_satisfiable(&proposition).unwrap().unwrap();
+    /// assert_eq!(model.get("P"), Some(true));
+    /// assert_eq!(model.get("Q"), Some(false));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if the proposition contains invalid or void expressions.
+    /// Returns [`RaaError::GaveUp`] if a limit of the [`ProverConfig`] is exceeded.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    /// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+    /// [`ProverConfig`]: crate::config::ProverConfig
+    pub fn is_satisfiable(&self, proposition: &Proposition) -> Result<Option<Assignment>> {
+        self.is_consistent(std::slice::from_ref(proposition))
+    }
+
+    /// Checks whether a set of propositions is consistent, i.e. whether there is an assignment
+    /// under which all of them are TRUE.
+    ///
+    /// All propositions are put at the root of a single truth tree, so no conjunction needs to be
+    /// built by hand. If all branches close, the set is inconsistent and `None` is returned.
+    /// Otherwise a model is read off an open branch. The empty set is consistent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{
+    ///     prover::Prover,
+    ///     proposition::Proposition,
+    ///     implication::Implication,
+    ///     negation::Negation,
+    /// };
+    ///
+    /// // {P → Q, P, ¬Q} is inconsistent
+    /// let propositions = [
+    ///     Proposition::Implication(Implication {
+    ///         left: Box::new("P".into()),
+    ///         right: Box::new("Q".into()),
+    ///     }),
+    ///     "P".into(),
+    ///     Proposition::Negation(Negation {
+    ///         inner: Box::new("Q".into()),
+    ///     }),
+    /// ];
+    ///
+    /// let prover = Prover::new();
+    /// assert!(prover.is_consistent(&propositions).unwrap().is_none());
+    /// assert!(prover.is_consistent(&propositions[..2]).unwrap().is_some());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RaaError`] if a proposition contains invalid or void expressions.
+    /// Returns [`RaaError::GaveUp`] if a limit of the [`ProverConfig`] is exceeded.
+    ///
+    /// [`RaaError`]: crate::errors::RaaError
+    /// [`RaaError::GaveUp`]: crate::errors::RaaError::GaveUp
+    /// [`ProverConfig`]: crate::config::ProverConfig
+    pub fn is_consistent(&self, propositions: &[Proposition]) -> Result<Option<Assignment>> {
+        if propositions.is_empty() {
+            return Ok(Some(Assignment::new()));
+        }
+        let mut arena = PropositionArena::new();
+        let roots = propositions
+            .iter()
+            .map(|p| arena.intern(p))
+            .collect::<Result<Vec<_>>>()?;
+        let (prove_result, graph) = self.develop_tree(
+            &mut arena,
+            &roots,
+            false,
+            false,
+            &mut Budget::new(&self.config),
+        )?;
+        if prove_result == ProveResult::Falsified {
+            Ok(None)
+        } else {
+            let variables = propositions
+                .iter()
+                .flat_map(Proposition::get_variables)
+                .collect();
+            Ok(Self::open_branch_assignment(&arena, &graph, variables))
+        }
+    }
+
+    /// Returns a lazy enumeration of the models of a proposition.
+    ///
+    /// The truth tree of the proposition is developed completely when the first model is
+    /// requested. The literals of each open branch fix some variables, the models of the branch
+    /// assign both values to the remaining ones. Models of several branches are yielded once.
+    /// The limits of the [`ProverConfig`] apply to the truth tree. See [`Models`] for projections
+    /// and limits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{prover::Prover, proposition::Proposition, implication::Implication};
+    ///
+    /// // P → Q is TRUE under three of the four assignments
+    /// let proposition = Proposition::Implication(Implication {
+    ///     left: Box::new("P".into()),
+    ///     right: Box::new("Q".into()),
+    /// });
+    ///
+    /// let models = Prover::new().models(&proposition).collect::<Result<Vec<_>, _>>().unwrap();
+    /// assert_eq!(models.len(), 3);
+    /// assert!(models.iter().all(|m| m.get("P") != Some(true) || m.get("Q") == Some(true)));
+    /// ```
+    ///
+    /// [`ProverConfig`]: crate::config::ProverConfig
+    pub fn models(&self, proposition: &Proposition) -> Models<'_> {
+        Models::branches(self, proposition)
+    }
+
+    // Returns the literals of the open branches of the completely developed truth tree of the
+    // proposition.
+    pub(crate) fn open_branches(
         &self,
         proposition: &Proposition,
-        graph: &mut PropositionTree,
-        negate: bool,
-    ) -> Result<()> {
-        // The node id of the root is stored in `self.root`.
-        *self.root.borrow_mut() = match proposition {
-            Proposition::Void => Err(RaaError::VoidExpression)?,
-            _ => graph.add_node((
-                if negate {
-                    Proposition::Negation(Negation {
-                        inner: Box::new(proposition.clone()),
-                    })
-                } else {
-                    proposition.clone()
+    ) -> Result<Vec<BTreeMap<String, bool>>> {
+        let mut arena = PropositionArena::new();
+        let (_, graph) = self.try_prove(
+            &mut arena,
+            proposition,
+            false,
+            true,
+            &mut Budget::new(&self.config),
+        )?;
+        let literal = |node_id: NodeIndex| match arena.node(graph[node_id].proposition) {
+            PropNode::Atom(v) => Some((arena.variables()[v].clone(), true)),
+            PropNode::Negation(inner) => match arena.node(inner) {
+                PropNode::Atom(v) => Some((arena.variables()[v].clone(), false)),
+                _ => None,
+            },
+            _ => None,
+        };
+        Ok(leaf_nodes(&graph)
+            .into_iter()
+            .filter(|leaf| graph[*leaf].state != TransformationState::Closed)
+            .map(|leaf| branch_nodes(&graph, leaf).filter_map(literal).collect())
+            .collect())
+    }
+
+    // Decides a proposition with as little tableau work as possible.
+    // The truth tree of the negated proposition either closes or yields a counterexample. A model
+    // is then searched among a few candidate assignments first. Only if none of them satisfies
+    // the proposition, a second truth tree of the proposition itself is developed.
+    // If `exhaustive` is false the truth trees are only developed until an open branch is complete.
+    fn decide(
+        &self,
+        arena: &mut PropositionArena,
+        proposition: &Proposition,
+        exhaustive: bool,
+        budget: &mut Budget,
+    ) -> Result<(Witness, Vec<PropositionTree>)> {
+        let variables = proposition.get_variables();
+        let (prove_result, graph) = self.try_prove(arena, proposition, true, exhaustive, budget)?;
+        if prove_result == ProveResult::Proven {
+            let model = variables.into_iter().map(|v| (v, false)).collect();
+            return Ok((
+                Witness {
+                    result: prove_result,
+                    counterexample: None,
+                    model: Some(model),
+                    stats: budget.stats(),
                 },
-                TransformationState::default(),
-            )),
+                vec![graph],
+            ));
+        }
+        let counterexample = Self::open_branch_assignment(arena, &graph, variables.clone());
+        let mut graphs = vec![graph];
+        let mut model = match &counterexample {
+            Some(counterexample) => Self::guess_model(proposition, counterexample)?,
+            None => None,
         };
-        Ok(())
+        if model.is_none() {
+            let (prove_result, graph) =
+                self.try_prove(arena, proposition, false, exhaustive, budget)?;
+            if prove_result != ProveResult::Falsified {
+                model = Self::open_branch_assignment(arena, &graph, variables);
+            }
+            graphs.push(graph);
+        }
+        Ok((
+            Witness {
+                result: if model.is_some() {
+                    ProveResult::Contingent
+                } else {
+                    ProveResult::Falsified
+                },
+                counterexample,
+                model,
+                stats: budget.stats(),
+            },
+            graphs,
+        ))
+    }
+
+    // Evaluates the proposition under a few assignments that are cheap to derive from a
+    // counterexample: the complementary assignment, all variables true and all variables false.
+    fn guess_model(
+        proposition: &Proposition,
+        counterexample: &Assignment,
+    ) -> Result<Option<Assignment>> {
+        let candidates: [fn(bool) -> bool; 3] = [|b| !b, |_| true, |_| false];
+        for value_of in candidates {
+            let candidate: Assignment = counterexample
+                .iter()
+                .map(|(v, b)| (v.to_string(), value_of(b)))
+                .collect();
+            if proposition.evaluate(&candidate)? {
+                return Ok(Some(candidate));
+            }
+        }
+        Ok(None)
     }
 
-    fn transform(proposition: &Proposition) -> Result<(Vec<Proposition>, Vec<Proposition>)> {
-        Ok(match proposition {
-            Proposition::Void => Err(RaaError::VoidExpression)?,
-            Proposition::Atom(a) => {
-                debug!("Transfer Atom {a}");
-                (vec![], vec![])
+    fn try_prove(
+        &self,
+        arena: &mut PropositionArena,
+        proposition: &Proposition,
+        negated: bool,
+        exhaustive: bool,
+        budget: &mut Budget,
+    ) -> Result<(ProveResult, PropositionTree)> {
+        let root = arena.intern(proposition)?;
+        let root = if negated { arena.negate(root) } else { root };
+        self.develop_tree(arena, &[root], negated, exhaustive, budget)
+    }
+
+    // An argument is valid if its premises together with the negated conclusion can be refuted.
+    fn try_entail(
+        &self,
+        arena: &mut PropositionArena,
+        premises: &[Proposition],
+        conclusion: &Proposition,
+        exhaustive: bool,
+        budget: &mut Budget,
+    ) -> Result<(ProveResult, PropositionTree)> {
+        let mut roots = premises
+            .iter()
+            .map(|p| arena.intern(p))
+            .collect::<Result<Vec<_>>>()?;
+        let conclusion = arena.intern(conclusion)?;
+        roots.push(arena.negate(conclusion));
+        self.develop_tree(arena, &roots, true, exhaustive, budget)
+    }
+
+    // Develops the truth tree of the given root propositions.
+    // The nodes are decomposed in the order given by the expansion strategy of the prover.
+    // Unless `exhaustive` is set, the development stops as soon as a branch is complete and still
+    // open. Such a tree can't be closed anymore and its result is `Contingent`.
+    // Each step is charged to the budget, which fails if a limit of the configuration is exceeded.
+    fn develop_tree(
+        &self,
+        arena: &mut PropositionArena,
+        roots: &[PropId],
+        negated: bool,
+        exhaustive: bool,
+        budget: &mut Budget,
+    ) -> Result<(ProveResult, PropositionTree)> {
+        let mut prove_result = ProveResult::Processing;
+        let mut development = TreeDevelopment::new(roots, arena, &self.config.strategy);
+        while prove_result == ProveResult::Processing {
+            development.trace(negated);
+            prove_result = development.step(negated);
+            budget.charge(&development.stats)?;
+            if !exhaustive
+                && prove_result == ProveResult::Processing
+                && development.has_complete_open_branch()
+            {
+                prove_result = ProveResult::Contingent;
             }
-            Proposition::Negation(n) => match &*n.inner {
+        }
+        development.trace(negated);
+        budget.finish(&development.stats);
+        Ok((prove_result, development.graph))
+    }
+
+    // Reads the variable assignment off the first completely developed open branch of a tree.
+    // The literals on the branch are consistent, otherwise the branch would have been closed.
+    fn open_branch_assignment(
+        arena: &PropositionArena,
+        graph: &PropositionTree,
+        variables: BTreeSet<String>,
+    ) -> Option<Assignment> {
+        let open_leaf = complete_open_leaf(graph)?;
+        let mut values = variables
+            .into_iter()
+            .map(|v| (v, false))
+            .collect::<BTreeMap<_, _>>();
+        for node_id in branch_nodes(graph, open_leaf) {
+            match arena.node(graph[node_id].proposition) {
+                PropNode::Atom(v) => {
+                    values.insert(arena.variables()[v].clone(), true);
+                }
+                PropNode::Negation(inner) => {
+                    if let PropNode::Atom(v) = arena.node(inner) {
+                        values.insert(arena.variables()[v].clone(), false);
+                    }
+                }
+                _ => (),
+            }
+        }
+        Some(values.into())
+    }
+
+    fn transform(
+        arena: &mut PropositionArena,
+        proposition: PropId,
+    ) -> (Option<Rule>, Vec<PropId>, Vec<PropId>) {
+        match arena.node(proposition) {
+            // Constants are not decomposed, FALSE closes its branch
+            PropNode::Constant(value) => {
+                debug!("Transfer Constant {}", Proposition::Constant(value));
+                (None, vec![], vec![])
+            }
+            PropNode::Atom(v) => {
+                debug!("Transfer Atom {}", arena.variables()[v]);
+                (None, vec![], vec![])
+            }
+            PropNode::Negation(n) => match arena.node(n) {
                 // Rule "Double negation"
                 // A branch that contains a proposition in the form ¬¬A can be appended with A.
-                Proposition::Negation(Negation { inner }) => {
-                    debug!("Transfer double negation {n} =>");
-                    debug!("    [{}]", inner);
+                PropNode::Negation(inner) => {
+                    debug!(
+                        "Transfer double negation {} =>",
+                        arena.to_proposition(proposition)
+                    );
+                    debug!("    [{}]", arena.to_proposition(inner));
                     debug!("    []");
-                    (vec![(**inner).clone()], vec![])
+                    (Some(Rule::DoubleNegation), vec![inner], vec![])
                 }
                 // Rule "Negated biimplication"
                 // A branch that contains a proposition in the form ¬(A <-> B) can be appended with
                 // two new branches, one containing A and ¬B and one containing ¬A and B.
-                Proposition::BiImplication(BiImplication { left, right }) => {
-                    debug!("Transfer negated biimplication {n} =>");
-                    debug!("    [{}, !{}]", left, right);
-                    debug!("    [!{}, {}]", left, right);
+                PropNode::BiImplication(left, right) => {
+                    debug!(
+                        "Transfer negated biimplication {} =>",
+                        arena.to_proposition(proposition)
+                    );
+                    debug!(
+                        "    [{}, !{}]",
+                        arena.to_proposition(left),
+                        arena.to_proposition(right)
+                    );
+                    debug!(
+                        "    [!{}, {}]",
+                        arena.to_proposition(left),
+                        arena.to_proposition(right)
+                    );
                     (
-                        vec![
-                            (**left).clone(),
-                            Proposition::Negation(Negation {
-                                inner: Box::new((**right).clone()),
-                            }),
-                        ],
-                        vec![
-                            Proposition::Negation(Negation {
-                                inner: Box::new((**left).clone()),
-                            }),
-                            (**right).clone(),
-                        ],
+                        Some(Rule::NegatedBiImplication),
+                        vec![left, arena.negate(right)],
+                        vec![arena.negate(left), right],
                     )
                 }
                 // Rule "Negated implication"
                 // A branch that contains a proposition in the form ¬(A -> B) can be appended
                 // with A and ¬B.
-                Proposition::Implication(Implication { left, right }) => {
-                    debug!("Transfer negated implication {n} =>");
-                    debug!("    [{}, !{}]", left, right);
+                PropNode::Implication(left, right) => {
+                    debug!(
+                        "Transfer negated implication {} =>",
+                        arena.to_proposition(proposition)
+                    );
+                    debug!(
+                        "    [{}, !{}]",
+                        arena.to_proposition(left),
+                        arena.to_proposition(right)
+                    );
                     debug!("    []");
                     (
-                        vec![
-                            (**left).clone(),
-                            Proposition::Negation(Negation {
-                                inner: Box::new((**right).clone()),
-                            }),
-                        ],
+                        Some(Rule::NegatedImplication),
+                        vec![left, arena.negate(right)],
                         vec![],
                     )
                 }
                 // Rule "Negated disjunction"
                 // A branch that contains a proposition in the form ¬(A ∨ B) can be appended
                 // with ¬A and ¬B.
-                Proposition::Disjunction(Disjunction { left, right }) => {
-                    debug!("Transfer negated disjunction {n} =>");
-                    debug!("    [!{}, !{}]", left, right);
+                PropNode::Disjunction(left, right) => {
+                    debug!(
+                        "Transfer negated disjunction {} =>",
+                        arena.to_proposition(proposition)
+                    );
+                    debug!(
+                        "    [!{}, !{}]",
+                        arena.to_proposition(left),
+                        arena.to_proposition(right)
+                    );
                     debug!("    []");
                     (
-                        vec![
-                            Proposition::Negation(Negation {
-                                inner: Box::new((**left).clone()),
-                            }),
-                            Proposition::Negation(Negation {
-                                inner: Box::new((**right).clone()),
-                            }),
-                        ],
+                        Some(Rule::NegatedDisjunction),
+                        vec![arena.negate(left), arena.negate(right)],
                         vec![],
                     )
                 }
                 // Rule "Negated conjunction"
                 // A branch that contains a proposition in the form ¬(A ∧ B) can be appended
                 // with two new branches ¬A and ¬B.
-                Proposition::Conjunction(Conjunction { left, right }) => {
-                    debug!("Transfer negated conjunction {n} =>");
-                    debug!("    [!{}]", left);
-                    debug!("    [!{}]", right);
+                PropNode::Conjunction(left, right) => {
+                    debug!(
+                        "Transfer negated conjunction {} =>",
+                        arena.to_proposition(proposition)
+                    );
+                    debug!("    [!{}]", arena.to_proposition(left));
+                    debug!("    [!{}]", arena.to_proposition(right));
+                    (
+                        Some(Rule::NegatedConjunction),
+                        vec![arena.negate(left)],
+                        vec![arena.negate(right)],
+                    )
+                }
+                // Rule "Negated exclusive disjunction"
+                // A branch that contains a proposition in the form ¬(A ⊕ B) can be appended with
+                // two new branches, one containing A and B and one containing ¬A and ¬B.
+                PropNode::ExclusiveDisjunction(left, right) => {
+                    debug!(
+                        "Transfer negated exclusive disjunction {} =>",
+                        arena.to_proposition(proposition)
+                    );
+                    debug!(
+                        "    [{}, {}]",
+                        arena.to_proposition(left),
+                        arena.to_proposition(right)
+                    );
+                    debug!(
+                        "    [!{}, !{}]",
+                        arena.to_proposition(left),
+                        arena.to_proposition(right)
+                    );
+                    (
+                        Some(Rule::NegatedExclusiveDisjunction),
+                        vec![left, right],
+                        vec![arena.negate(left), arena.negate(right)],
+                    )
+                }
+                // Rule "Negated alternative denial"
+                // A branch that contains a proposition in the form ¬(A ↑ B) can be appended
+                // with A and B.
+                PropNode::AlternativeDenial(left, right) => {
+                    debug!(
+                        "Transfer negated alternative denial {} =>",
+                        arena.to_proposition(proposition)
+                    );
+                    debug!(
+                        "    [{}, {}]",
+                        arena.to_proposition(left),
+                        arena.to_proposition(right)
+                    );
+                    debug!("    []");
                     (
-                        vec![Proposition::Negation(Negation {
-                            inner: Box::new((**left).clone()),
-                        })],
-                        vec![Proposition::Negation(Negation {
-                            inner: Box::new((**right).clone()),
-                        })],
+                        Some(Rule::NegatedAlternativeDenial),
+                        vec![left, right],
+                        vec![],
                     )
                 }
+                // Rule "Negated joint denial"
+                // A branch that contains a proposition in the form ¬(A ↓ B) can be appended
+                // with two new branches A and B.
+                PropNode::JointDenial(left, right) => {
+                    debug!(
+                        "Transfer negated joint denial {} =>",
+                        arena.to_proposition(proposition)
+                    );
+                    debug!("    [{}]", arena.to_proposition(left));
+                    debug!("    [{}]", arena.to_proposition(right));
+                    (Some(Rule::NegatedJointDenial), vec![left], vec![right])
+                }
                 // Otherwise no changes
-                _ => (vec![], vec![]),
+                PropNode::Constant(_) | PropNode::Atom(_) => (None, vec![], vec![]),
             },
             // Rule "Implication"
             // A branch that contains a proposition in the form A -> B can be appended with two
             // new branches ¬A and B.
-            Proposition::Implication(Implication { left, right }) => {
-                debug!("Transfer implication {proposition} =>");
-                debug!("    [!{}]", left);
-                debug!("    [{}]", right);
+            PropNode::Implication(left, right) => {
+                debug!(
+                    "Transfer implication {} =>",
+                    arena.to_proposition(proposition)
+                );
+                debug!("    [!{}]", arena.to_proposition(left));
+                debug!("    [{}]", arena.to_proposition(right));
                 (
-                    vec![Proposition::Negation(Negation {
-                        inner: Box::new((**left).clone()),
-                    })],
-                    vec![(**right).clone()],
+                    Some(Rule::Implication),
+                    vec![arena.negate(left)],
+                    vec![right],
                 )
             }
             // Rule "BiImplication"
             // A branch that contains a proposition in the form A <-> B can be appended with two
             // new branches, one containing A and B and one containing ¬A and ¬B.
-            Proposition::BiImplication(BiImplication { left, right }) => {
-                debug!("Transfer biimplication {proposition} =>");
-                debug!("    [{}, {}]", left, right);
-                debug!("    [!{}, !{}]", left, right);
+            PropNode::BiImplication(left, right) => {
+                debug!(
+                    "Transfer biimplication {} =>",
+                    arena.to_proposition(proposition)
+                );
+                debug!(
+                    "    [{}, {}]",
+                    arena.to_proposition(left),
+                    arena.to_proposition(right)
+                );
+                debug!(
+                    "    [!{}, !{}]",
+                    arena.to_proposition(left),
+                    arena.to_proposition(right)
+                );
                 (
-                    vec![(**left).clone(), (**right).clone()],
-                    vec![
-                        Proposition::Negation(Negation {
-                            inner: Box::new((**left).clone()),
-                        }),
-                        Proposition::Negation(Negation {
-                            inner: Box::new((**right).clone()),
-                        }),
-                    ],
+                    Some(Rule::BiImplication),
+                    vec![left, right],
+                    vec![arena.negate(left), arena.negate(right)],
                 )
             }
             // Rule "Disjunction"
             // A branch that contains a proposition in the form A ∨ B can be appended with two
             // new branches A and B.
-            Proposition::Disjunction(Disjunction { left, right }) => {
-                debug!("Transfer disjunction {proposition} =>");
-                debug!("    [{}]", left);
-                debug!("    [{}]", right);
-                (vec![(**left).clone()], vec![(**right).clone()])
+            PropNode::Disjunction(left, right) => {
+                debug!(
+                    "Transfer disjunction {} =>",
+                    arena.to_proposition(proposition)
+                );
+                debug!("    [{}]", arena.to_proposition(left));
+                debug!("    [{}]", arena.to_proposition(right));
+                (Some(Rule::Disjunction), vec![left], vec![right])
             }
             // Rule "Conjunction"
             // A branch that contains a proposition in the form A ∧ B can be appended with A and
             // B.
-            Proposition::Conjunction(Conjunction { left, right }) => {
-                debug!("Transfer conjunction {proposition} =>");
-                debug!("    [{}, {}]", left, right);
+            PropNode::Conjunction(left, right) => {
+                debug!(
+                    "Transfer conjunction {} =>",
+                    arena.to_proposition(proposition)
+                );
+                debug!(
+                    "    [{}, {}]",
+                    arena.to_proposition(left),
+                    arena.to_proposition(right)
+                );
                 debug!("    []");
-                (vec![(**left).clone(), (**right).clone()], vec![])
+                (Some(Rule::Conjunction), vec![left, right], vec![])
             }
-        })
+            // Rule "Exclusive disjunction"
+            // A branch that contains a proposition in the form A ⊕ B can be appended with two
+            // new branches, one containing A and ¬B and one containing ¬A and B.
+            PropNode::ExclusiveDisjunction(left, right) => {
+                debug!(
+                    "Transfer exclusive disjunction {} =>",
+                    arena.to_proposition(proposition)
+                );
+                debug!(
+                    "    [{}, !{}]",
+                    arena.to_proposition(left),
+                    arena.to_proposition(right)
+                );
+                debug!(
+                    "    [!{}, {}]",
+                    arena.to_proposition(left),
+                    arena.to_proposition(right)
+                );
+                (
+                    Some(Rule::ExclusiveDisjunction),
+                    vec![left, arena.negate(right)],
+                    vec![arena.negate(left), right],
+                )
+            }
+            // Rule "Alternative denial"
+            // A branch that contains a proposition in the form A ↑ B can be appended with two
+            // new branches ¬A and ¬B.
+            PropNode::AlternativeDenial(left, right) => {
+                debug!(
+                    "Transfer alternative denial {} =>",
+                    arena.to_proposition(proposition)
+                );
+                debug!("    [!{}]", arena.to_proposition(left));
+                debug!("    [!{}]", arena.to_proposition(right));
+                (
+                    Some(Rule::AlternativeDenial),
+                    vec![arena.negate(left)],
+                    vec![arena.negate(right)],
+                )
+            }
+            // Rule "Joint denial"
+            // A branch that contains a proposition in the form A ↓ B can be appended with ¬A
+            // and ¬B.
+            PropNode::JointDenial(left, right) => {
+                debug!(
+                    "Transfer joint denial {} =>",
+                    arena.to_proposition(proposition)
+                );
+                debug!(
+                    "    [!{}, !{}]",
+                    arena.to_proposition(left),
+                    arena.to_proposition(right)
+                );
+                debug!("    []");
+                (
+                    Some(Rule::JointDenial),
+                    vec![arena.negate(left), arena.negate(right)],
+                    vec![],
+                )
+            }
+        }
     }
+}
 
-    fn inner_prove(&self, graph: &mut PropositionTree, negated: bool) -> Result<ProveResult> {
-        let mut changed = false;
-        if let Some(unprocessed_node) = self.find_unprocessed_node(graph) {
-            let (to_add_left, to_add_right) = Self::transform(&graph[unprocessed_node].0)?;
-            let leafs_to_append = unclosed_leaf_nodes_of(graph, unprocessed_node);
-            for leaf_node_id in leafs_to_append {
-                let mut last_parent_node = leaf_node_id;
-                for p in &to_add_left {
-                    let new_node_id = graph.add_node((p.clone(), TransformationState::default()));
-                    graph.add_edge(last_parent_node, new_node_id, ());
-                    last_parent_node = new_node_id;
-                }
-                last_parent_node = leaf_node_id;
-                for p in &to_add_right {
-                    let new_node_id = graph.add_node((p.clone(), TransformationState::default()));
-                    graph.add_edge(last_parent_node, new_node_id, ());
-                    last_parent_node = new_node_id;
-                }
+// The resources used by a single call of the prover.
+// The statistics cover the finished truth trees, the tree under development keeps its own.
+struct Budget<'a> {
+    config: &'a ProverConfig,
+    started: Instant,
+    stats: ProofStats,
+}
+
+impl<'a> Budget<'a> {
+    fn new(config: &'a ProverConfig) -> Self {
+        Self {
+            config,
+            started: Instant::now(),
+            stats: ProofStats::default(),
+        }
+    }
+
+    // Checks the limits against the finished trees together with the tree under development.
+    // Fails with the statistics so far if a limit is exceeded.
+    fn charge(&self, tree: &ProofStats) -> Result<()> {
+        match self.config.exceeded(
+            self.stats.nodes + tree.nodes,
+            self.stats.steps + tree.steps,
+            self.started,
+        ) {
+            Some(limit) => {
+                let mut stats = self.stats();
+                stats.add(tree);
+                Err(RaaError::GaveUp { limit, stats })
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn finish(&mut self, tree: &ProofStats) {
+        self.stats.add(tree);
+    }
+
+    fn stats(&self) -> ProofStats {
+        ProofStats {
+            elapsed: self.started.elapsed(),
+            ..self.stats.clone()
+        }
+    }
+}
+
+// The state of a truth tree under development.
+// The agenda contains the unprocessed nodes ordered by their priority and their index. The
+// expansions of the propositions are cached because the strategies need them before a node is
+// decomposed.
+struct TreeDevelopment<'a> {
+    graph: PropositionTree,
+    arena: &'a mut PropositionArena,
+    strategy: &'a ExpansionStrategy,
+    open_branches: Vec<OpenBranch>,
+    agenda: BTreeSet<(i64, NodeIndex)>,
+    expansions: HashMap<PropId, Expansion>,
+    stats: ProofStats,
+}
+
+impl<'a> TreeDevelopment<'a> {
+    // We insert a (possibly negated) variant of our proposition and try to refute it later.
+    // Multiple root propositions, like the premises of an argument, are inserted as a single
+    // branch. If the roots contradict each other, the tree has no open branch from the start.
+    fn new(
+        roots: &[PropId],
+        arena: &'a mut PropositionArena,
+        strategy: &'a ExpansionStrategy,
+    ) -> Self {
+        let mut development = Self {
+            graph: PropositionTree::new(),
+            arena,
+            strategy,
+            open_branches: Vec::new(),
+            agenda: BTreeSet::new(),
+            expansions: HashMap::new(),
+            stats: ProofStats {
+                nodes: roots.len(),
+                branches_opened: 1,
+                max_depth: roots.len(),
+                ..Default::default()
+            },
+        };
+        let mut branch = OpenBranch::default();
+        let mut closing_pair = None;
+        for proposition in roots {
+            let node_id = development
+                .graph
+                .add_node(TreeNode::new(*proposition, None));
+            if !branch.nodes.is_empty() {
+                development.graph.add_edge(branch.leaf, node_id, ());
             }
-            graph[unprocessed_node].1 = TransformationState::Transformed;
-            changed |= true;
+            closing_pair = closing_pair.or(branch.push(node_id, *proposition, development.arena));
         }
-        if changed {
-            self.update_branches_closed_state(graph)?;
+        match closing_pair {
+            Some(_) => development.close_branch(branch.leaf, closing_pair),
+            None => development.open_branches.push(branch),
         }
-        self.check_all_branches_closed(graph, changed, negated)
+        for node_id in development.graph.node_indices().collect::<Vec<_>>() {
+            development.schedule(node_id);
+        }
+        development
     }
 
-    fn update_branches_closed_state(&self, graph: &mut PropositionTree) -> Result<ProveResult> {
-        let leaf_node_ids = leaf_nodes(graph);
-        let mut leaf_nodes_to_close = vec![];
-        for leaf_node_id in leaf_node_ids {
-            let (_, transformation_state) = &graph[leaf_node_id];
-            if *transformation_state == TransformationState::Closed {
+    // Decomposes the next node of the agenda on all open branches it belongs to.
+    fn step(&mut self, negated: bool) -> ProveResult {
+        let Some(unprocessed_node) = self.next_node() else {
+            // No branch can be developed anymore. A single root node like ⊥ can close the tree
+            // without any step.
+            return if self.open_branches.is_empty() {
+                Self::closed_result(negated)
+            } else {
+                ProveResult::Contingent
+            };
+        };
+        self.stats.steps += 1;
+        if self.graph[unprocessed_node].state == TransformationState::Closed {
+            // Closed leaves are not decomposed anymore.
+            return ProveResult::Processing;
+        }
+        let expansion = self.expansions[&self.graph[unprocessed_node].proposition].clone();
+        let mut developed_branches = Vec::with_capacity(self.open_branches.len());
+        let mut applied = false;
+        for mut branch in std::mem::take(&mut self.open_branches) {
+            if !branch.nodes.contains(&unprocessed_node) {
+                developed_branches.push(branch);
                 continue;
             }
-            // We compare all nodes along the path from the root to this edge node with each other.
-            let ancestors = self.ancestors(&*graph, leaf_node_id);
-            let pairs = pairwise(&ancestors);
-            if pairs.iter().any(|(i, j)| {
-                let (a, _) = &graph[**i];
-                let (b, _) = &graph[**j];
-                match (a, b) {
-                    (Proposition::Negation(n), _) => *n.inner == *b,
-                    (_, Proposition::Negation(n)) => *n.inner == *a,
-                    _ => false,
-                }
-            }) {
-                leaf_nodes_to_close.push(leaf_node_id);
+            applied = true;
+            branch.unprocessed -= 1;
+            let right_branch = (!expansion.right.is_empty()).then(|| branch.clone());
+            if right_branch.is_some() {
+                self.stats.branches_opened += 1;
+            }
+            if self.extend_branch(&mut branch, &expansion.left, unprocessed_node) {
+                developed_branches.push(branch);
+            }
+            if let Some(mut branch) = right_branch
+                && self.extend_branch(&mut branch, &expansion.right, unprocessed_node)
+            {
+                developed_branches.push(branch);
             }
         }
-        for leaf_node_id in leaf_nodes_to_close {
-            let (_, transformation_state) = &mut graph[leaf_node_id];
-            *transformation_state = TransformationState::Closed;
+        self.open_branches = developed_branches;
+        // A node whose branches have all been closed meanwhile stays undecomposed.
+        if applied {
+            self.graph[unprocessed_node].state = TransformationState::Transformed;
+            self.graph[unprocessed_node].rule = expansion.rule;
+            if let Some(rule) = expansion.rule {
+                *self.stats.rule_applications.entry(rule).or_default() += 1;
+            }
         }
-        Ok(ProveResult::Processing)
-    }
+        self.stats.nodes = self.graph.node_count();
 
-    fn check_all_branches_closed(
-        &self,
-        graph: &PropositionTree,
-        changed: bool,
-        negated: bool,
-    ) -> Result<ProveResult> {
-        let leaf_node_ids = leaf_nodes(graph);
-        let all_closed = leaf_node_ids
-            .iter()
-            .all(|i| graph[*i].1 == TransformationState::Closed);
-        if all_closed {
+        if self.open_branches.is_empty() {
             // This means all branches contain contradictions!
-            Ok(if negated {
-                // We used the negated proposition to refute it which indirectly proved it's truth.
-                ProveResult::Proven
-            } else {
-                // We used the original proposition to refute it which directly falsified it.
-                ProveResult::Falsified
-            })
-        } else if changed {
-            // We need to continue until no branches can be developed anymore.
-            Ok(ProveResult::Processing)
+            Self::closed_result(negated)
         } else {
-            Ok(ProveResult::Contingent)
+            // We need to continue until no branches can be developed anymore.
+            ProveResult::Processing
         }
     }
 
-    fn ancestors(&self, graph: &PropositionTree, node_id: NodeIndex) -> Vec<NodeIndex> {
-        let paths = all_simple_paths::<Vec<_>, _, std::hash::RandomState>(
-            graph,
-            *self.root.borrow(),
-            node_id,
-            0,
-            None,
-        )
-        .collect::<Vec<_>>();
-        // Tree constraint:
-        // At most one path should exist from root to this end node.
-        debug_assert!(paths.len() < 2, "length was {}", paths.len());
-        if paths.is_empty() {
-            vec![*self.root.borrow()]
+    fn closed_result(negated: bool) -> ProveResult {
+        if negated {
+            // We used the negated proposition to refute it which indirectly proved it's truth.
+            ProveResult::Proven
         } else {
-            // Path constraint:
-            // The target node should be contained in the list of ancestors.
-            debug_assert!(paths[0].contains(&node_id));
-            paths[0].clone()
+            // We used the original proposition to refute it which directly falsified it.
+            ProveResult::Falsified
         }
     }
 
-    fn find_unprocessed_node(&self, graph: &mut PropositionTree) -> Option<NodeIndex> {
-        graph.node_indices().find(|i| {
-            let node = &graph[*i];
-            node.1 == TransformationState::Unprocessed
-        })
+    // A complete open branch has no unprocessed nodes left.
+    fn has_complete_open_branch(&self) -> bool {
+        self.open_branches.iter().any(|b| b.unprocessed == 0)
     }
-}
 
-/// Generate pairs of elements in a slice without redundances.
-fn pairwise<T>(v: &[T]) -> Vec<(&T, &T)> {
-    let mut result = Vec::with_capacity(v.len());
-    for (i, a) in v.iter().enumerate() {
-        for b in v.iter().skip(i + 1) {
-            result.push((a, b));
+    // Appends the propositions to the branch.
+    // Returns false if the branch has been closed.
+    fn extend_branch(
+        &mut self,
+        branch: &mut OpenBranch,
+        propositions: &[PropId],
+        derived_from: NodeIndex,
+    ) -> bool {
+        let mut closing_pair = None;
+        for p in propositions {
+            let new_node_id = self.graph.add_node(TreeNode::new(*p, Some(derived_from)));
+            self.graph.add_edge(branch.leaf, new_node_id, ());
+            closing_pair = closing_pair.or(branch.push(new_node_id, *p, self.arena));
+            self.schedule(new_node_id);
+        }
+        self.stats.max_depth = self.stats.max_depth.max(branch.nodes.len());
+        if closing_pair.is_some() {
+            self.close_branch(branch.leaf, closing_pair);
         }
+        closing_pair.is_none()
+    }
+
+    fn close_branch(
+        &mut self,
+        leaf_node_id: NodeIndex,
+        closing_pair: Option<(NodeIndex, NodeIndex)>,
+    ) {
+        self.stats.branches_closed += 1;
+        let leaf_node = &mut self.graph[leaf_node_id];
+        leaf_node.state = TransformationState::Closed;
+        leaf_node.closed_by = closing_pair;
+    }
+
+    // Puts a new node on the agenda.
+    fn schedule(&mut self, node_id: NodeIndex) {
+        let proposition = self.graph[node_id].proposition;
+        if !self.expansions.contains_key(&proposition) {
+            let (rule, left, right) = Prover::transform(self.arena, proposition);
+            self.expansions
+                .insert(proposition, Expansion { rule, left, right });
+        }
+        let priority = if self.strategy.is_static() {
+            self.priority(node_id)
+        } else {
+            0
+        };
+        self.agenda.insert((priority, node_id));
+    }
+
+    // Removes the node with the smallest priority from the agenda.
+    // Priorities of dynamic strategies are recalculated for all nodes on the agenda.
+    fn next_node(&mut self) -> Option<NodeIndex> {
+        let next = if self.strategy.is_static() {
+            self.agenda.first().copied()?
+        } else {
+            // Dynamic priorities aren't stored in the agenda.
+            let (_, node_id) = self
+                .agenda
+                .iter()
+                .map(|(_, node_id)| (self.priority(*node_id), *node_id))
+                .min()?;
+            (0, node_id)
+        };
+        self.agenda.remove(&next);
+        Some(next.1)
+    }
+
+    fn priority(&self, node_id: NodeIndex) -> i64 {
+        let proposition = self.graph[node_id].proposition;
+        self.strategy.priority(&Candidate {
+            node: node_id,
+            proposition,
+            arena: self.arena,
+            expansion: &self.expansions[&proposition],
+            open_branches: &self.open_branches,
+        })
+    }
+
+    fn trace(&self, negated: bool) {
+        trace!(
+            "{}{:?}",
+            if negated { "neg " } else { "" },
+            Dot::with_attr_getters(
+                &self.graph,
+                &[Config::EdgeNoLabel, Config::NodeNoLabel],
+                &|_, _| { String::default() },
+                &|g, n| {
+                    format!(
+                        "label = \"{} ({}, {})\"",
+                        self.arena.to_proposition(g[n.0].proposition),
+                        n.0.index(),
+                        g[n.0].state
+                    )
+                }
+            )
+        );
     }
-    result
 }
 
 fn leaf_nodes(graph: &PropositionTree) -> Vec<NodeIndex> {
@@ -571,44 +1752,38 @@ fn leaf_nodes(graph: &PropositionTree) -> Vec<NodeIndex> {
         .collect::<Vec<NodeIndex>>()
 }
 
-fn unclosed_leaf_nodes_of(graph: &PropositionTree, start: NodeIndex) -> Vec<NodeIndex> {
-    let mut dfs = DfsPostOrder::new(graph, start);
-    let mut result = Vec::new();
-    while let Some(i) = dfs.next(graph) {
-        if graph[i].1 != TransformationState::Closed && graph.neighbors(i).count() == 0 {
-            result.push(i)
+// Returns the leaf of the first branch that is open and whose nodes are all processed.
+// Such a branch stays open no matter how the rest of the tree develops.
+fn complete_open_leaf(graph: &PropositionTree) -> Option<NodeIndex> {
+    leaf_nodes(graph).into_iter().find(|leaf| {
+        if graph[*leaf].state == TransformationState::Closed {
+            return false;
         }
-    }
-    result
+        branch_nodes(graph, *leaf).all(|i| graph[i].state != TransformationState::Unprocessed)
+    })
+}
+
+// Iterates the nodes of the branch that ends at the given node, from the leaf up to the root.
+fn branch_nodes(graph: &PropositionTree, leaf: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+    std::iter::successors(Some(leaf), |i| {
+        graph.neighbors_directed(*i, Direction::Incoming).next()
+    })
 }
 
 #[cfg(test)]
 mod test {
 
     use crate::{
+        arena::PropositionArena,
         conjunction::Conjunction,
+        disjunction::Disjunction,
         implication::Implication,
+        negation::Negation,
         proposition::Proposition,
-        prover::{pairwise, ProveResult, Prover},
+        prover::{Budget, ProveResult, Prover, TransformationState, complete_open_leaf},
+        strategy::ExpansionStrategy,
     };
 
-    #[test]
-    fn test_pairwise() {
-        let v = vec!['i', 'j', 'k', 'l'];
-        let pairs = pairwise(&v);
-        assert_eq!(
-            vec![
-                (&'i', &'j'),
-                (&'i', &'k'),
-                (&'i', &'l'),
-                (&'j', &'k'),
-                (&'j', &'l'),
-                (&'k', &'l')
-            ],
-            pairs
-        );
-    }
-
     #[test]
     fn test_solve() {
         // Logically True - Modus Ponens
@@ -628,4 +1803,88 @@ mod test {
             Prover::new().prove(&proposition).unwrap()
         );
     }
+
+    #[test]
+    fn test_stop_at_complete_open_branch() {
+        // p | (q & r)
+        let proposition = Proposition::Disjunction(Disjunction {
+            left: Box::new("p".into()),
+            right: Box::new(Proposition::Conjunction(Conjunction {
+                left: Box::new("q".into()),
+                right: Box::new("r".into()),
+            })),
+        });
+        let prover = Prover::new();
+        let mut arena = PropositionArena::new();
+
+        let (result, graph) = prover
+            .try_prove(
+                &mut arena,
+                &proposition,
+                false,
+                false,
+                &mut Budget::new(prover.config()),
+            )
+            .unwrap();
+        assert_eq!(ProveResult::Contingent, result);
+        assert!(complete_open_leaf(&graph).is_some());
+        assert!(
+            graph
+                .node_weights()
+                .any(|n| n.state == TransformationState::Unprocessed)
+        );
+
+        let (result, graph) = prover
+            .try_prove(
+                &mut arena,
+                &proposition,
+                false,
+                true,
+                &mut Budget::new(prover.config()),
+            )
+            .unwrap();
+        assert_eq!(ProveResult::Contingent, result);
+        assert!(
+            graph
+                .node_weights()
+                .all(|n| n.state != TransformationState::Unprocessed)
+        );
+    }
+
+    #[test]
+    fn test_skip_node_of_closed_branches() {
+        // (p & !p) & (q | r)
+        let proposition = Proposition::Conjunction(Conjunction {
+            left: Box::new(Proposition::Conjunction(Conjunction {
+                left: Box::new("p".into()),
+                right: Box::new(Proposition::Negation(Negation {
+                    inner: Box::new("p".into()),
+                })),
+            })),
+            right: Box::new(Proposition::Disjunction(Disjunction {
+                left: Box::new("q".into()),
+                right: Box::new("r".into()),
+            })),
+        });
+        let prover = Prover::with_strategy(ExpansionStrategy::AlphaFirst);
+        let mut arena = PropositionArena::new();
+
+        // The disjunction is only reached after its branch has been closed.
+        let (result, graph) = prover
+            .try_prove(
+                &mut arena,
+                &proposition,
+                false,
+                true,
+                &mut Budget::new(prover.config()),
+            )
+            .unwrap();
+        assert_eq!(ProveResult::Falsified, result);
+        let disjunction = graph
+            .node_weights()
+            .find(|n| arena.to_proposition(n.proposition).to_string() == "(q | r)")
+            .unwrap();
+        assert_eq!(TransformationState::Unprocessed, disjunction.state);
+        assert_eq!(None, disjunction.rule);
+    }
 }
@@ -1,20 +1,82 @@
-use crate::raa_tt_grammar_trait::{RaaTt, RaaTtGrammarTrait};
+use crate::argument::Statement;
+use crate::raa_tt_grammar_trait::{Conditional, RaaTt, RaaTtGrammarTrait};
 #[allow(unused_imports)]
 use parol_runtime::{Result, Token};
 use std::fmt::{Debug, Display, Error, Formatter};
 
+/// The grouping of chained implications without parentheses
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Associativity {
+    /// `a -> b -> c` is read as `(a -> b) -> c`
+    #[default]
+    Left,
+    /// `a -> b -> c` is read as `a -> (b -> c)` like in most logic texts
+    Right,
+}
+
 ///
 /// Data structure that implements the semantic actions for our RaaTt grammar
 ///
 #[derive(Debug, Default)]
 pub struct RaaTtGrammar<'t> {
     pub raa_tt: Option<RaaTt<'t>>,
+
+    /// The associativity of implication chains in [`statements`](Self::statements)
+    pub implication: Associativity,
+
+    /// A warning for each chain of implications without parentheses, whose meaning depends on
+    /// the associativity
+    pub warnings: Vec<String>,
 }
 
 impl RaaTtGrammar<'_> {
     pub fn new() -> Self {
         RaaTtGrammar::default()
     }
+
+    /// Creates a grammar that reads implication chains with the given associativity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{
+    ///     argument::Statement,
+    ///     raa_tt_grammar::{Associativity, RaaTtGrammar},
+    ///     raa_tt_parser::parse,
+    /// };
+    ///
+    /// let mut grammar = RaaTtGrammar::with_associativity(Associativity::Right);
+    /// parse("p -> q -> p", "example", &mut grammar).unwrap();
+    /// let [Statement::Proposition(proposition)] = &grammar.statements()[..] else {
+    ///     panic!("Expected a proposition");
+    /// };
+    /// assert_eq!(proposition.to_string(), "(p -> (q -> p))");
+    /// assert_eq!(grammar.warnings.len(), 1);
+    /// ```
+    pub fn with_associativity(implication: Associativity) -> Self {
+        RaaTtGrammar {
+            implication,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the propositions and arguments of the parsed input.
+    ///
+    /// Implication chains are grouped according to [`implication`](Self::implication).
+    pub fn statements(&self) -> Vec<Statement> {
+        let Some(raa_tt) = &self.raa_tt else {
+            return Vec::new();
+        };
+        let mut statements =
+            Statement::from_theorems(&raa_tt.theorems, self.implication).collect::<Vec<_>>();
+        for item in &raa_tt.raa_tt_list {
+            statements.push(Statement::from_syntax(item, self.implication));
+            if let Some(opt) = &item.raa_tt_opt {
+                statements.extend(Statement::from_theorems(&opt.theorems, self.implication));
+            }
+        }
+        statements
+    }
 }
 
 impl Display for RaaTt<'_> {
@@ -38,4 +100,22 @@ impl<'t> RaaTtGrammarTrait<'t> for RaaTtGrammar<'t> {
         self.raa_tt = Some(arg.clone());
         Ok(())
     }
+
+    /// Semantic action for non-terminal 'Conditional'
+    fn conditional(&mut self, arg: &Conditional<'t>) -> Result<()> {
+        if let Some(second) = arg.conditional_list.get(1) {
+            let location = &second.cond.cond.location;
+            let grouping = match self.implication {
+                Associativity::Left => "(a -> b) -> c",
+                Associativity::Right => "a -> (b -> c)",
+            };
+            self.warnings.push(format!(
+                "{}:{}:{}: An implication chain without parentheses like a -> b -> c is read as {grouping}",
+                location.file_name.display(),
+                location.start_line,
+                location.start_column,
+            ));
+        }
+        Ok(())
+    }
 }
@@ -28,11 +28,26 @@ pub trait RaaTtGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Nand'
+    fn nand(&mut self, _arg: &Nand<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Xor'
+    fn xor(&mut self, _arg: &Xor<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Or'
     fn or(&mut self, _arg: &Or<'t>) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Nor'
+    fn nor(&mut self, _arg: &Nor<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Cond'
     fn cond(&mut self, _arg: &Cond<'t>) -> Result<()> {
         Ok(())
@@ -53,6 +68,26 @@ pub trait RaaTtGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Comma'
+    fn comma(&mut self, _arg: &Comma<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Turnstile'
+    fn turnstile(&mut self, _arg: &Turnstile<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'True'
+    fn r#true(&mut self, _arg: &True<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'False'
+    fn r#false(&mut self, _arg: &False<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Var'
     fn var(&mut self, _arg: &Var<'t>) -> Result<()> {
         Ok(())
@@ -63,6 +98,16 @@ pub trait RaaTtGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Theorems'
+    fn theorems(&mut self, _arg: &Theorems<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Argument'
+    fn argument(&mut self, _arg: &Argument<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Biconditional'
     fn biconditional(&mut self, _arg: &Biconditional<'t>) -> Result<()> {
         Ok(())
@@ -78,16 +123,36 @@ pub trait RaaTtGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'DisjunctionOp'
+    fn disjunction_op(&mut self, _arg: &DisjunctionOp<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ExclusiveDisjunction'
+    fn exclusive_disjunction(&mut self, _arg: &ExclusiveDisjunction<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Conjunction'
     fn conjunction(&mut self, _arg: &Conjunction<'t>) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ConjunctionOp'
+    fn conjunction_op(&mut self, _arg: &ConjunctionOp<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Negation'
     fn negation(&mut self, _arg: &Negation<'t>) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Constant'
+    fn constant(&mut self, _arg: &Constant<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Factor'
     fn factor(&mut self, _arg: &Factor<'t>) -> Result<()> {
         Ok(())
@@ -104,7 +169,84 @@ pub trait RaaTtGrammarTrait<'t> {
 //
 
 ///
-/// Type derived for production 24
+/// Type derived for production 35
+///
+/// `DisjunctionOp: Or;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DisjunctionOpOr<'t> {
+    pub or: Or<'t>,
+}
+
+///
+/// Type derived for production 36
+///
+/// `DisjunctionOp: Nor;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DisjunctionOpNor<'t> {
+    pub nor: Nor<'t>,
+}
+
+///
+/// Type derived for production 43
+///
+/// `ConjunctionOp: And;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ConjunctionOpAnd<'t> {
+    pub and: And<'t>,
+}
+
+///
+/// Type derived for production 44
+///
+/// `ConjunctionOp: Nand;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ConjunctionOpNand<'t> {
+    pub nand: Nand<'t>,
+}
+
+///
+/// Type derived for production 46
+///
+/// `Constant: True;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ConstantTrue<'t> {
+    pub r#true: True<'t>,
+}
+
+///
+/// Type derived for production 47
+///
+/// `Constant: False;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ConstantFalse<'t> {
+    pub r#false: False<'t>,
+}
+
+///
+/// Type derived for production 48
+///
+/// `Factor: Constant;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct FactorConstant<'t> {
+    pub constant: Constant<'t>,
+}
+
+///
+/// Type derived for production 49
 ///
 /// `Factor: Var;`
 ///
@@ -115,7 +257,7 @@ pub struct FactorVar<'t> {
 }
 
 ///
-/// Type derived for production 25
+/// Type derived for production 50
 ///
 /// `Factor: Negation;`
 ///
@@ -126,7 +268,7 @@ pub struct FactorNegation<'t> {
 }
 
 ///
-/// Type derived for production 26
+/// Type derived for production 51
 ///
 /// `Factor: LPar^ /* Clipped */ Biconditional RPar^ /* Clipped */;`
 ///
@@ -147,7 +289,26 @@ pub struct FactorLParBiconditionalRPar<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct And<'t> {
-    pub and: Token<'t>, /* & */
+    pub and: Token<'t>, /* &|∧|\x2F\\|and */
+}
+
+///
+/// Type derived for non-terminal Argument
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Argument<'t> {
+    pub argument_list: Vec<ArgumentList<'t>>,
+    pub biconditional: Biconditional<'t>,
+}
+
+///
+/// Type derived for non-terminal ArgumentList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ArgumentList<'t> {
+    pub biconditional: Biconditional<'t>,
 }
 
 ///
@@ -156,7 +317,7 @@ pub struct And<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct BiCond<'t> {
-    pub bi_cond: Token<'t>, /* <-> */
+    pub bi_cond: Token<'t>, /* <->|↔|<=> */
 }
 
 ///
@@ -178,13 +339,22 @@ pub struct BiconditionalList<'t> {
     pub conditional: Conditional<'t>,
 }
 
+///
+/// Type derived for non-terminal Comma
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Comma<'t> {
+    pub comma: Token<'t>, /* , */
+}
+
 ///
 /// Type derived for non-terminal Cond
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Cond<'t> {
-    pub cond: Token<'t>, /* -> */
+    pub cond: Token<'t>, /* ->|→|=> */
 }
 
 ///
@@ -203,6 +373,7 @@ pub struct Conditional<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct ConditionalList<'t> {
+    pub cond: Cond<'t>,
     pub disjunction: Disjunction<'t>,
 }
 
@@ -222,16 +393,37 @@ pub struct Conjunction<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct ConjunctionList<'t> {
+    pub conjunction_op: ConjunctionOp<'t>,
     pub factor: Factor<'t>,
 }
 
+///
+/// Type derived for non-terminal ConjunctionOp
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ConjunctionOp<'t> {
+    And(ConjunctionOpAnd<'t>),
+    Nand(ConjunctionOpNand<'t>),
+}
+
+///
+/// Type derived for non-terminal Constant
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Constant<'t> {
+    True(ConstantTrue<'t>),
+    False(ConstantFalse<'t>),
+}
+
 ///
 /// Type derived for non-terminal Disjunction
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Disjunction<'t> {
-    pub conjunction: Conjunction<'t>,
+    pub exclusive_disjunction: ExclusiveDisjunction<'t>,
     pub disjunction_list: Vec<DisjunctionList<'t>>,
 }
 
@@ -241,6 +433,36 @@ pub struct Disjunction<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct DisjunctionList<'t> {
+    pub disjunction_op: DisjunctionOp<'t>,
+    pub exclusive_disjunction: ExclusiveDisjunction<'t>,
+}
+
+///
+/// Type derived for non-terminal DisjunctionOp
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum DisjunctionOp<'t> {
+    Or(DisjunctionOpOr<'t>),
+    Nor(DisjunctionOpNor<'t>),
+}
+
+///
+/// Type derived for non-terminal ExclusiveDisjunction
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ExclusiveDisjunction<'t> {
+    pub conjunction: Conjunction<'t>,
+    pub exclusive_disjunction_list: Vec<ExclusiveDisjunctionList<'t>>,
+}
+
+///
+/// Type derived for non-terminal ExclusiveDisjunctionList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ExclusiveDisjunctionList<'t> {
     pub conjunction: Conjunction<'t>,
 }
 
@@ -250,11 +472,21 @@ pub struct DisjunctionList<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum Factor<'t> {
+    Constant(FactorConstant<'t>),
     Var(FactorVar<'t>),
     Negation(FactorNegation<'t>),
     LParBiconditionalRPar(FactorLParBiconditionalRPar<'t>),
 }
 
+///
+/// Type derived for non-terminal False
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct False<'t> {
+    pub r#false: Token<'t>, /* F|⊥|false */
+}
+
 ///
 /// Type derived for non-terminal LPar
 ///
@@ -264,6 +496,15 @@ pub struct LPar<'t> {
     pub l_par: Token<'t>, /* ( */
 }
 
+///
+/// Type derived for non-terminal Nand
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Nand<'t> {
+    pub nand: Token<'t>, /* NAND|↑|nand */
+}
+
 ///
 /// Type derived for non-terminal Negation
 ///
@@ -273,13 +514,22 @@ pub struct Negation<'t> {
     pub factor: Factor<'t>,
 }
 
+///
+/// Type derived for non-terminal Nor
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Nor<'t> {
+    pub nor: Token<'t>, /* NOR|↓|nor */
+}
+
 ///
 /// Type derived for non-terminal Not
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Not<'t> {
-    pub not: Token<'t>, /* ! */
+    pub not: Token<'t>, /* !|¬|~|not */
 }
 
 ///
@@ -288,7 +538,7 @@ pub struct Not<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Or<'t> {
-    pub or: Token<'t>, /* | */
+    pub or: Token<'t>, /* \||∨|\\\x2F|or */
 }
 
 ///
@@ -306,6 +556,7 @@ pub struct RPar<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct RaaTt<'t> {
+    pub theorems: Theorems<'t>,
     pub raa_tt_list: Vec<RaaTtList<'t>>,
 }
 
@@ -316,6 +567,53 @@ pub struct RaaTt<'t> {
 #[derive(Debug, Clone)]
 pub struct RaaTtList<'t> {
     pub biconditional: Biconditional<'t>,
+    pub raa_tt_opt: Option<RaaTtOpt<'t>>,
+}
+
+///
+/// Type derived for non-terminal RaaTtOpt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct RaaTtOpt<'t> {
+    pub argument: Argument<'t>,
+    pub theorems: Theorems<'t>,
+}
+
+///
+/// Type derived for non-terminal Theorems
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Theorems<'t> {
+    pub theorems_list: Vec<TheoremsList<'t>>,
+}
+
+///
+/// Type derived for non-terminal TheoremsList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TheoremsList<'t> {
+    pub biconditional: Biconditional<'t>,
+}
+
+///
+/// Type derived for non-terminal True
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct True<'t> {
+    pub r#true: Token<'t>, /* T|⊤|true */
+}
+
+///
+/// Type derived for non-terminal Turnstile
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Turnstile<'t> {
+    pub turnstile: Token<'t>, /* \|-|⊢ */
 }
 
 ///
@@ -327,6 +625,15 @@ pub struct Var<'t> {
     pub var: Token<'t>, /* [a-z][_a-zA-Z0-9]* */
 }
 
+///
+/// Type derived for non-terminal Xor
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Xor<'t> {
+    pub xor: Token<'t>, /* \^|⊕|xor */
+}
+
 // -------------------------------------------------------------------------------------------------
 
 ///
@@ -336,25 +643,42 @@ pub struct Var<'t> {
 #[derive(Debug, Clone)]
 pub enum ASTType<'t> {
     And(And<'t>),
+    Argument(Argument<'t>),
+    ArgumentList(Vec<ArgumentList<'t>>),
     BiCond(BiCond<'t>),
     Biconditional(Biconditional<'t>),
     BiconditionalList(Vec<BiconditionalList<'t>>),
+    Comma(Comma<'t>),
     Cond(Cond<'t>),
     Conditional(Conditional<'t>),
     ConditionalList(Vec<ConditionalList<'t>>),
     Conjunction(Conjunction<'t>),
     ConjunctionList(Vec<ConjunctionList<'t>>),
+    ConjunctionOp(ConjunctionOp<'t>),
+    Constant(Constant<'t>),
     Disjunction(Disjunction<'t>),
     DisjunctionList(Vec<DisjunctionList<'t>>),
+    DisjunctionOp(DisjunctionOp<'t>),
+    ExclusiveDisjunction(ExclusiveDisjunction<'t>),
+    ExclusiveDisjunctionList(Vec<ExclusiveDisjunctionList<'t>>),
     Factor(Factor<'t>),
+    False(False<'t>),
     LPar(LPar<'t>),
+    Nand(Nand<'t>),
     Negation(Negation<'t>),
+    Nor(Nor<'t>),
     Not(Not<'t>),
     Or(Or<'t>),
     RPar(RPar<'t>),
     RaaTt(RaaTt<'t>),
     RaaTtList(Vec<RaaTtList<'t>>),
+    RaaTtOpt(Option<RaaTtOpt<'t>>),
+    Theorems(Theorems<'t>),
+    TheoremsList(Vec<TheoremsList<'t>>),
+    True(True<'t>),
+    Turnstile(Turnstile<'t>),
     Var(Var<'t>),
+    Xor(Xor<'t>),
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -420,7 +744,7 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 0:
     ///
-    /// `Not: '!';`
+    /// `Not: /!|¬|~|not/;`
     ///
     #[parol_runtime::function_name::named]
     fn not(&mut self, not: &ParseTreeType<'t>) -> Result<()> {
@@ -436,7 +760,7 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 1:
     ///
-    /// `And: '&';`
+    /// `And: /&|∧|\x2F\\|and/;`
     ///
     #[parol_runtime::function_name::named]
     fn and(&mut self, and: &ParseTreeType<'t>) -> Result<()> {
@@ -452,7 +776,39 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 2:
     ///
-    /// `Or: '|';`
+    /// `Nand: /NAND|↑|nand/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn nand(&mut self, nand: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let nand = nand.token()?.clone();
+        let nand_built = Nand { nand };
+        // Calling user action here
+        self.user_grammar.nand(&nand_built)?;
+        self.push(ASTType::Nand(nand_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 3:
+    ///
+    /// `Xor: /\^|⊕|xor/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn xor(&mut self, xor: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let xor = xor.token()?.clone();
+        let xor_built = Xor { xor };
+        // Calling user action here
+        self.user_grammar.xor(&xor_built)?;
+        self.push(ASTType::Xor(xor_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 4:
+    ///
+    /// `Or: /\||∨|\\\x2F|or/;`
     ///
     #[parol_runtime::function_name::named]
     fn or(&mut self, or: &ParseTreeType<'t>) -> Result<()> {
@@ -466,9 +822,25 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 3:
+    /// Semantic action for production 5:
+    ///
+    /// `Nor: /NOR|↓|nor/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn nor(&mut self, nor: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let nor = nor.token()?.clone();
+        let nor_built = Nor { nor };
+        // Calling user action here
+        self.user_grammar.nor(&nor_built)?;
+        self.push(ASTType::Nor(nor_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 6:
     ///
-    /// `Cond: '->';`
+    /// `Cond: /->|→|=>/;`
     ///
     #[parol_runtime::function_name::named]
     fn cond(&mut self, cond: &ParseTreeType<'t>) -> Result<()> {
@@ -482,9 +854,9 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 4:
+    /// Semantic action for production 7:
     ///
-    /// `BiCond: '<->';`
+    /// `BiCond: /<->|↔|<=>/;`
     ///
     #[parol_runtime::function_name::named]
     fn bi_cond(&mut self, bi_cond: &ParseTreeType<'t>) -> Result<()> {
@@ -498,7 +870,7 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 5:
+    /// Semantic action for production 8:
     ///
     /// `LPar: '(';`
     ///
@@ -514,7 +886,7 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 6:
+    /// Semantic action for production 9:
     ///
     /// `RPar: ')';`
     ///
@@ -530,60 +902,137 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 7:
+    /// Semantic action for production 10:
     ///
-    /// `Var: /[a-z][_a-zA-Z0-9]*/;`
+    /// `Comma: ',';`
     ///
     #[parol_runtime::function_name::named]
-    fn var(&mut self, var: &ParseTreeType<'t>) -> Result<()> {
+    fn comma(&mut self, comma: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let var = var.token()?.clone();
-        let var_built = Var { var };
+        let comma = comma.token()?.clone();
+        let comma_built = Comma { comma };
         // Calling user action here
-        self.user_grammar.var(&var_built)?;
-        self.push(ASTType::Var(var_built), context);
+        self.user_grammar.comma(&comma_built)?;
+        self.push(ASTType::Comma(comma_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 8:
+    /// Semantic action for production 11:
     ///
-    /// `RaaTt: RaaTtList /* Vec */;`
+    /// `Turnstile: /\|-|⊢/;`
     ///
     #[parol_runtime::function_name::named]
-    fn raa_tt(&mut self, _raa_tt_list: &ParseTreeType<'t>) -> Result<()> {
+    fn turnstile(&mut self, turnstile: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let raa_tt_list = pop_and_reverse_item!(self, raa_tt_list, RaaTtList, context);
-        let raa_tt_built = RaaTt { raa_tt_list };
+        let turnstile = turnstile.token()?.clone();
+        let turnstile_built = Turnstile { turnstile };
         // Calling user action here
-        self.user_grammar.raa_tt(&raa_tt_built)?;
-        self.push(ASTType::RaaTt(raa_tt_built), context);
+        self.user_grammar.turnstile(&turnstile_built)?;
+        self.push(ASTType::Turnstile(turnstile_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 9:
+    /// Semantic action for production 12:
     ///
-    /// `RaaTtList /* Vec<T>::Push */: Biconditional RaaTtList;`
+    /// `True: /T|⊤|true/;`
     ///
     #[parol_runtime::function_name::named]
-    fn raa_tt_list_0(
-        &mut self,
-        _biconditional: &ParseTreeType<'t>,
-        _raa_tt_list: &ParseTreeType<'t>,
-    ) -> Result<()> {
+    fn r#true(&mut self, r#true: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let mut raa_tt_list = pop_item!(self, raa_tt_list, RaaTtList, context);
-        let biconditional = pop_item!(self, biconditional, Biconditional, context);
-        let raa_tt_list_0_built = RaaTtList { biconditional };
-        // Add an element to the vector
+        let r#true = r#true.token()?.clone();
+        let r#true_built = True { r#true };
+        // Calling user action here
+        self.user_grammar.r#true(&r#true_built)?;
+        self.push(ASTType::True(r#true_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 13:
+    ///
+    /// `False: /F|⊥|false/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn r#false(&mut self, r#false: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r#false = r#false.token()?.clone();
+        let r#false_built = False { r#false };
+        // Calling user action here
+        self.user_grammar.r#false(&r#false_built)?;
+        self.push(ASTType::False(r#false_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 14:
+    ///
+    /// `Var: /[a-z][_a-zA-Z0-9]*/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn var(&mut self, var: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let var = var.token()?.clone();
+        let var_built = Var { var };
+        // Calling user action here
+        self.user_grammar.var(&var_built)?;
+        self.push(ASTType::Var(var_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 15:
+    ///
+    /// `RaaTt: Theorems RaaTtList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn raa_tt(
+        &mut self,
+        _theorems: &ParseTreeType<'t>,
+        _raa_tt_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let raa_tt_list = pop_and_reverse_item!(self, raa_tt_list, RaaTtList, context);
+        let theorems = pop_item!(self, theorems, Theorems, context);
+        let raa_tt_built = RaaTt {
+            theorems,
+            raa_tt_list,
+        };
+        // Calling user action here
+        self.user_grammar.raa_tt(&raa_tt_built)?;
+        self.push(ASTType::RaaTt(raa_tt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 16:
+    ///
+    /// `RaaTtList /* Vec<T>::Push */: Biconditional RaaTtOpt /* Option */ RaaTtList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn raa_tt_list_0(
+        &mut self,
+        _biconditional: &ParseTreeType<'t>,
+        _raa_tt_opt: &ParseTreeType<'t>,
+        _raa_tt_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut raa_tt_list = pop_item!(self, raa_tt_list, RaaTtList, context);
+        let raa_tt_opt = pop_item!(self, raa_tt_opt, RaaTtOpt, context);
+        let biconditional = pop_item!(self, biconditional, Biconditional, context);
+        let raa_tt_list_0_built = RaaTtList {
+            raa_tt_opt,
+            biconditional,
+        };
+        // Add an element to the vector
         raa_tt_list.push(raa_tt_list_0_built);
         self.push(ASTType::RaaTtList(raa_tt_list), context);
         Ok(())
     }
 
-    /// Semantic action for production 10:
+    /// Semantic action for production 17:
     ///
     /// `RaaTtList /* Vec<T>::New */: ;`
     ///
@@ -596,7 +1045,152 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 11:
+    /// Semantic action for production 18:
+    ///
+    /// `RaaTtOpt /* Option<T>::Some */: Argument Theorems;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn raa_tt_opt_0(
+        &mut self,
+        _argument: &ParseTreeType<'t>,
+        _theorems: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let theorems = pop_item!(self, theorems, Theorems, context);
+        let argument = pop_item!(self, argument, Argument, context);
+        let raa_tt_opt_0_built = RaaTtOpt { argument, theorems };
+        self.push(ASTType::RaaTtOpt(Some(raa_tt_opt_0_built)), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 19:
+    ///
+    /// `RaaTtOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn raa_tt_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::RaaTtOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 20:
+    ///
+    /// `Theorems: TheoremsList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn theorems(&mut self, _theorems_list: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let theorems_list = pop_and_reverse_item!(self, theorems_list, TheoremsList, context);
+        let theorems_built = Theorems { theorems_list };
+        // Calling user action here
+        self.user_grammar.theorems(&theorems_built)?;
+        self.push(ASTType::Theorems(theorems_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 21:
+    ///
+    /// `TheoremsList /* Vec<T>::Push */: Turnstile^ /* Clipped */ Biconditional TheoremsList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn theorems_list_0(
+        &mut self,
+        _turnstile: &ParseTreeType<'t>,
+        _biconditional: &ParseTreeType<'t>,
+        _theorems_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut theorems_list = pop_item!(self, theorems_list, TheoremsList, context);
+        let biconditional = pop_item!(self, biconditional, Biconditional, context);
+        self.pop(context);
+        let theorems_list_0_built = TheoremsList { biconditional };
+        // Add an element to the vector
+        theorems_list.push(theorems_list_0_built);
+        self.push(ASTType::TheoremsList(theorems_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 22:
+    ///
+    /// `TheoremsList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn theorems_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let theorems_list_1_built = Vec::new();
+        self.push(ASTType::TheoremsList(theorems_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 23:
+    ///
+    /// `Argument: ArgumentList /* Vec */ Turnstile^ /* Clipped */ Biconditional;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn argument(
+        &mut self,
+        _argument_list: &ParseTreeType<'t>,
+        _turnstile: &ParseTreeType<'t>,
+        _biconditional: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let biconditional = pop_item!(self, biconditional, Biconditional, context);
+        self.pop(context);
+        let argument_list = pop_and_reverse_item!(self, argument_list, ArgumentList, context);
+        let argument_built = Argument {
+            argument_list,
+            biconditional,
+        };
+        // Calling user action here
+        self.user_grammar.argument(&argument_built)?;
+        self.push(ASTType::Argument(argument_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 24:
+    ///
+    /// `ArgumentList /* Vec<T>::Push */: Comma^ /* Clipped */ Biconditional ArgumentList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn argument_list_0(
+        &mut self,
+        _comma: &ParseTreeType<'t>,
+        _biconditional: &ParseTreeType<'t>,
+        _argument_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut argument_list = pop_item!(self, argument_list, ArgumentList, context);
+        let biconditional = pop_item!(self, biconditional, Biconditional, context);
+        self.pop(context);
+        let argument_list_0_built = ArgumentList { biconditional };
+        // Add an element to the vector
+        argument_list.push(argument_list_0_built);
+        self.push(ASTType::ArgumentList(argument_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 25:
+    ///
+    /// `ArgumentList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn argument_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let argument_list_1_built = Vec::new();
+        self.push(ASTType::ArgumentList(argument_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 26:
     ///
     /// `Biconditional: Conditional BiconditionalList /* Vec */;`
     ///
@@ -621,7 +1215,7 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 12:
+    /// Semantic action for production 27:
     ///
     /// `BiconditionalList /* Vec<T>::Push */: BiCond^ /* Clipped */ Conditional BiconditionalList;`
     ///
@@ -645,7 +1239,7 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 13:
+    /// Semantic action for production 28:
     ///
     /// `BiconditionalList /* Vec<T>::New */: ;`
     ///
@@ -661,7 +1255,7 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 14:
+    /// Semantic action for production 29:
     ///
     /// `Conditional: Disjunction ConditionalList /* Vec */;`
     ///
@@ -686,9 +1280,9 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 15:
+    /// Semantic action for production 30:
     ///
-    /// `ConditionalList /* Vec<T>::Push */: Cond^ /* Clipped */ Disjunction ConditionalList;`
+    /// `ConditionalList /* Vec<T>::Push */: Cond Disjunction ConditionalList;`
     ///
     #[parol_runtime::function_name::named]
     fn conditional_list_0(
@@ -701,15 +1295,15 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut conditional_list = pop_item!(self, conditional_list, ConditionalList, context);
         let disjunction = pop_item!(self, disjunction, Disjunction, context);
-        self.pop(context);
-        let conditional_list_0_built = ConditionalList { disjunction };
+        let cond = pop_item!(self, cond, Cond, context);
+        let conditional_list_0_built = ConditionalList { disjunction, cond };
         // Add an element to the vector
         conditional_list.push(conditional_list_0_built);
         self.push(ASTType::ConditionalList(conditional_list), context);
         Ok(())
     }
 
-    /// Semantic action for production 16:
+    /// Semantic action for production 31:
     ///
     /// `ConditionalList /* Vec<T>::New */: ;`
     ///
@@ -722,23 +1316,24 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 17:
+    /// Semantic action for production 32:
     ///
-    /// `Disjunction: Conjunction DisjunctionList /* Vec */;`
+    /// `Disjunction: ExclusiveDisjunction DisjunctionList /* Vec */;`
     ///
     #[parol_runtime::function_name::named]
     fn disjunction(
         &mut self,
-        _conjunction: &ParseTreeType<'t>,
+        _exclusive_disjunction: &ParseTreeType<'t>,
         _disjunction_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let disjunction_list =
             pop_and_reverse_item!(self, disjunction_list, DisjunctionList, context);
-        let conjunction = pop_item!(self, conjunction, Conjunction, context);
+        let exclusive_disjunction =
+            pop_item!(self, exclusive_disjunction, ExclusiveDisjunction, context);
         let disjunction_built = Disjunction {
-            conjunction,
+            exclusive_disjunction,
             disjunction_list,
         };
         // Calling user action here
@@ -747,30 +1342,34 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 18:
+    /// Semantic action for production 33:
     ///
-    /// `DisjunctionList /* Vec<T>::Push */: Or^ /* Clipped */ Conjunction DisjunctionList;`
+    /// `DisjunctionList /* Vec<T>::Push */: DisjunctionOp ExclusiveDisjunction DisjunctionList;`
     ///
     #[parol_runtime::function_name::named]
     fn disjunction_list_0(
         &mut self,
-        _or: &ParseTreeType<'t>,
-        _conjunction: &ParseTreeType<'t>,
+        _disjunction_op: &ParseTreeType<'t>,
+        _exclusive_disjunction: &ParseTreeType<'t>,
         _disjunction_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let mut disjunction_list = pop_item!(self, disjunction_list, DisjunctionList, context);
-        let conjunction = pop_item!(self, conjunction, Conjunction, context);
-        self.pop(context);
-        let disjunction_list_0_built = DisjunctionList { conjunction };
+        let exclusive_disjunction =
+            pop_item!(self, exclusive_disjunction, ExclusiveDisjunction, context);
+        let disjunction_op = pop_item!(self, disjunction_op, DisjunctionOp, context);
+        let disjunction_list_0_built = DisjunctionList {
+            exclusive_disjunction,
+            disjunction_op,
+        };
         // Add an element to the vector
         disjunction_list.push(disjunction_list_0_built);
         self.push(ASTType::DisjunctionList(disjunction_list), context);
         Ok(())
     }
 
-    /// Semantic action for production 19:
+    /// Semantic action for production 34:
     ///
     /// `DisjunctionList /* Vec<T>::New */: ;`
     ///
@@ -783,7 +1382,121 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 20:
+    /// Semantic action for production 35:
+    ///
+    /// `DisjunctionOp: Or;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn disjunction_op_0(&mut self, _or: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let or = pop_item!(self, or, Or, context);
+        let disjunction_op_0_built = DisjunctionOpOr { or };
+        let disjunction_op_0_built = DisjunctionOp::Or(disjunction_op_0_built);
+        // Calling user action here
+        self.user_grammar.disjunction_op(&disjunction_op_0_built)?;
+        self.push(ASTType::DisjunctionOp(disjunction_op_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 36:
+    ///
+    /// `DisjunctionOp: Nor;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn disjunction_op_1(&mut self, _nor: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let nor = pop_item!(self, nor, Nor, context);
+        let disjunction_op_1_built = DisjunctionOpNor { nor };
+        let disjunction_op_1_built = DisjunctionOp::Nor(disjunction_op_1_built);
+        // Calling user action here
+        self.user_grammar.disjunction_op(&disjunction_op_1_built)?;
+        self.push(ASTType::DisjunctionOp(disjunction_op_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 37:
+    ///
+    /// `ExclusiveDisjunction: Conjunction ExclusiveDisjunctionList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn exclusive_disjunction(
+        &mut self,
+        _conjunction: &ParseTreeType<'t>,
+        _exclusive_disjunction_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let exclusive_disjunction_list = pop_and_reverse_item!(
+            self,
+            exclusive_disjunction_list,
+            ExclusiveDisjunctionList,
+            context
+        );
+        let conjunction = pop_item!(self, conjunction, Conjunction, context);
+        let exclusive_disjunction_built = ExclusiveDisjunction {
+            conjunction,
+            exclusive_disjunction_list,
+        };
+        // Calling user action here
+        self.user_grammar
+            .exclusive_disjunction(&exclusive_disjunction_built)?;
+        self.push(
+            ASTType::ExclusiveDisjunction(exclusive_disjunction_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 38:
+    ///
+    /// `ExclusiveDisjunctionList /* Vec<T>::Push */: Xor^ /* Clipped */ Conjunction ExclusiveDisjunctionList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn exclusive_disjunction_list_0(
+        &mut self,
+        _xor: &ParseTreeType<'t>,
+        _conjunction: &ParseTreeType<'t>,
+        _exclusive_disjunction_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut exclusive_disjunction_list = pop_item!(
+            self,
+            exclusive_disjunction_list,
+            ExclusiveDisjunctionList,
+            context
+        );
+        let conjunction = pop_item!(self, conjunction, Conjunction, context);
+        self.pop(context);
+        let exclusive_disjunction_list_0_built = ExclusiveDisjunctionList { conjunction };
+        // Add an element to the vector
+        exclusive_disjunction_list.push(exclusive_disjunction_list_0_built);
+        self.push(
+            ASTType::ExclusiveDisjunctionList(exclusive_disjunction_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 39:
+    ///
+    /// `ExclusiveDisjunctionList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn exclusive_disjunction_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let exclusive_disjunction_list_1_built = Vec::new();
+        self.push(
+            ASTType::ExclusiveDisjunctionList(exclusive_disjunction_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 40:
     ///
     /// `Conjunction: Factor ConjunctionList /* Vec */;`
     ///
@@ -808,14 +1521,14 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 21:
+    /// Semantic action for production 41:
     ///
-    /// `ConjunctionList /* Vec<T>::Push */: And^ /* Clipped */ Factor ConjunctionList;`
+    /// `ConjunctionList /* Vec<T>::Push */: ConjunctionOp Factor ConjunctionList;`
     ///
     #[parol_runtime::function_name::named]
     fn conjunction_list_0(
         &mut self,
-        _and: &ParseTreeType<'t>,
+        _conjunction_op: &ParseTreeType<'t>,
         _factor: &ParseTreeType<'t>,
         _conjunction_list: &ParseTreeType<'t>,
     ) -> Result<()> {
@@ -823,15 +1536,18 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         trace!("{}", self.trace_item_stack(context));
         let mut conjunction_list = pop_item!(self, conjunction_list, ConjunctionList, context);
         let factor = pop_item!(self, factor, Factor, context);
-        self.pop(context);
-        let conjunction_list_0_built = ConjunctionList { factor };
+        let conjunction_op = pop_item!(self, conjunction_op, ConjunctionOp, context);
+        let conjunction_list_0_built = ConjunctionList {
+            factor,
+            conjunction_op,
+        };
         // Add an element to the vector
         conjunction_list.push(conjunction_list_0_built);
         self.push(ASTType::ConjunctionList(conjunction_list), context);
         Ok(())
     }
 
-    /// Semantic action for production 22:
+    /// Semantic action for production 42:
     ///
     /// `ConjunctionList /* Vec<T>::New */: ;`
     ///
@@ -844,7 +1560,41 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 23:
+    /// Semantic action for production 43:
+    ///
+    /// `ConjunctionOp: And;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn conjunction_op_0(&mut self, _and: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let and = pop_item!(self, and, And, context);
+        let conjunction_op_0_built = ConjunctionOpAnd { and };
+        let conjunction_op_0_built = ConjunctionOp::And(conjunction_op_0_built);
+        // Calling user action here
+        self.user_grammar.conjunction_op(&conjunction_op_0_built)?;
+        self.push(ASTType::ConjunctionOp(conjunction_op_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 44:
+    ///
+    /// `ConjunctionOp: Nand;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn conjunction_op_1(&mut self, _nand: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let nand = pop_item!(self, nand, Nand, context);
+        let conjunction_op_1_built = ConjunctionOpNand { nand };
+        let conjunction_op_1_built = ConjunctionOp::Nand(conjunction_op_1_built);
+        // Calling user action here
+        self.user_grammar.conjunction_op(&conjunction_op_1_built)?;
+        self.push(ASTType::ConjunctionOp(conjunction_op_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 45:
     ///
     /// `Negation: Not^ /* Clipped */ Factor;`
     ///
@@ -861,48 +1611,99 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 24:
+    /// Semantic action for production 46:
     ///
-    /// `Factor: Var;`
+    /// `Constant: True;`
     ///
     #[parol_runtime::function_name::named]
-    fn factor_0(&mut self, _var: &ParseTreeType<'t>) -> Result<()> {
+    fn constant_0(&mut self, _true: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let var = pop_item!(self, var, Var, context);
-        let factor_0_built = FactorVar { var };
-        let factor_0_built = Factor::Var(factor_0_built);
+        let r#true = pop_item!(self, r#true, True, context);
+        let constant_0_built = ConstantTrue { r#true };
+        let constant_0_built = Constant::True(constant_0_built);
+        // Calling user action here
+        self.user_grammar.constant(&constant_0_built)?;
+        self.push(ASTType::Constant(constant_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 47:
+    ///
+    /// `Constant: False;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn constant_1(&mut self, _false: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r#false = pop_item!(self, r#false, False, context);
+        let constant_1_built = ConstantFalse { r#false };
+        let constant_1_built = Constant::False(constant_1_built);
+        // Calling user action here
+        self.user_grammar.constant(&constant_1_built)?;
+        self.push(ASTType::Constant(constant_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 48:
+    ///
+    /// `Factor: Constant;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_0(&mut self, _constant: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let constant = pop_item!(self, constant, Constant, context);
+        let factor_0_built = FactorConstant { constant };
+        let factor_0_built = Factor::Constant(factor_0_built);
         // Calling user action here
         self.user_grammar.factor(&factor_0_built)?;
         self.push(ASTType::Factor(factor_0_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 25:
+    /// Semantic action for production 49:
+    ///
+    /// `Factor: Var;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn factor_1(&mut self, _var: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let var = pop_item!(self, var, Var, context);
+        let factor_1_built = FactorVar { var };
+        let factor_1_built = Factor::Var(factor_1_built);
+        // Calling user action here
+        self.user_grammar.factor(&factor_1_built)?;
+        self.push(ASTType::Factor(factor_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 50:
     ///
     /// `Factor: Negation;`
     ///
     #[parol_runtime::function_name::named]
-    fn factor_1(&mut self, _negation: &ParseTreeType<'t>) -> Result<()> {
+    fn factor_2(&mut self, _negation: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let negation = pop_item!(self, negation, Negation, context);
-        let factor_1_built = FactorNegation {
+        let factor_2_built = FactorNegation {
             negation: Box::new(negation),
         };
-        let factor_1_built = Factor::Negation(factor_1_built);
+        let factor_2_built = Factor::Negation(factor_2_built);
         // Calling user action here
-        self.user_grammar.factor(&factor_1_built)?;
-        self.push(ASTType::Factor(factor_1_built), context);
+        self.user_grammar.factor(&factor_2_built)?;
+        self.push(ASTType::Factor(factor_2_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 26:
+    /// Semantic action for production 51:
     ///
     /// `Factor: LPar^ /* Clipped */ Biconditional RPar^ /* Clipped */;`
     ///
     #[parol_runtime::function_name::named]
-    fn factor_2(
+    fn factor_3(
         &mut self,
         _l_par: &ParseTreeType<'t>,
         _biconditional: &ParseTreeType<'t>,
@@ -913,13 +1714,13 @@ impl<'t, 'u> RaaTtGrammarAuto<'t, 'u> {
         self.pop(context);
         let biconditional = pop_item!(self, biconditional, Biconditional, context);
         self.pop(context);
-        let factor_2_built = FactorLParBiconditionalRPar {
+        let factor_3_built = FactorLParBiconditionalRPar {
             biconditional: Box::new(biconditional),
         };
-        let factor_2_built = Factor::LParBiconditionalRPar(factor_2_built);
+        let factor_3_built = Factor::LParBiconditionalRPar(factor_3_built);
         // Calling user action here
-        self.user_grammar.factor(&factor_2_built)?;
-        self.push(ASTType::Factor(factor_2_built), context);
+        self.user_grammar.factor(&factor_3_built)?;
+        self.push(ASTType::Factor(factor_3_built), context);
         Ok(())
     }
 }
@@ -936,31 +1737,56 @@ impl<'t> UserActionsTrait<'t> for RaaTtGrammarAuto<'t, '_> {
         match prod_num {
             0 => self.not(&children[0]),
             1 => self.and(&children[0]),
-            2 => self.or(&children[0]),
-            3 => self.cond(&children[0]),
-            4 => self.bi_cond(&children[0]),
-            5 => self.l_par(&children[0]),
-            6 => self.r_par(&children[0]),
-            7 => self.var(&children[0]),
-            8 => self.raa_tt(&children[0]),
-            9 => self.raa_tt_list_0(&children[0], &children[1]),
-            10 => self.raa_tt_list_1(),
-            11 => self.biconditional(&children[0], &children[1]),
-            12 => self.biconditional_list_0(&children[0], &children[1], &children[2]),
-            13 => self.biconditional_list_1(),
-            14 => self.conditional(&children[0], &children[1]),
-            15 => self.conditional_list_0(&children[0], &children[1], &children[2]),
-            16 => self.conditional_list_1(),
-            17 => self.disjunction(&children[0], &children[1]),
-            18 => self.disjunction_list_0(&children[0], &children[1], &children[2]),
-            19 => self.disjunction_list_1(),
-            20 => self.conjunction(&children[0], &children[1]),
-            21 => self.conjunction_list_0(&children[0], &children[1], &children[2]),
-            22 => self.conjunction_list_1(),
-            23 => self.negation(&children[0], &children[1]),
-            24 => self.factor_0(&children[0]),
-            25 => self.factor_1(&children[0]),
-            26 => self.factor_2(&children[0], &children[1], &children[2]),
+            2 => self.nand(&children[0]),
+            3 => self.xor(&children[0]),
+            4 => self.or(&children[0]),
+            5 => self.nor(&children[0]),
+            6 => self.cond(&children[0]),
+            7 => self.bi_cond(&children[0]),
+            8 => self.l_par(&children[0]),
+            9 => self.r_par(&children[0]),
+            10 => self.comma(&children[0]),
+            11 => self.turnstile(&children[0]),
+            12 => self.r#true(&children[0]),
+            13 => self.r#false(&children[0]),
+            14 => self.var(&children[0]),
+            15 => self.raa_tt(&children[0], &children[1]),
+            16 => self.raa_tt_list_0(&children[0], &children[1], &children[2]),
+            17 => self.raa_tt_list_1(),
+            18 => self.raa_tt_opt_0(&children[0], &children[1]),
+            19 => self.raa_tt_opt_1(),
+            20 => self.theorems(&children[0]),
+            21 => self.theorems_list_0(&children[0], &children[1], &children[2]),
+            22 => self.theorems_list_1(),
+            23 => self.argument(&children[0], &children[1], &children[2]),
+            24 => self.argument_list_0(&children[0], &children[1], &children[2]),
+            25 => self.argument_list_1(),
+            26 => self.biconditional(&children[0], &children[1]),
+            27 => self.biconditional_list_0(&children[0], &children[1], &children[2]),
+            28 => self.biconditional_list_1(),
+            29 => self.conditional(&children[0], &children[1]),
+            30 => self.conditional_list_0(&children[0], &children[1], &children[2]),
+            31 => self.conditional_list_1(),
+            32 => self.disjunction(&children[0], &children[1]),
+            33 => self.disjunction_list_0(&children[0], &children[1], &children[2]),
+            34 => self.disjunction_list_1(),
+            35 => self.disjunction_op_0(&children[0]),
+            36 => self.disjunction_op_1(&children[0]),
+            37 => self.exclusive_disjunction(&children[0], &children[1]),
+            38 => self.exclusive_disjunction_list_0(&children[0], &children[1], &children[2]),
+            39 => self.exclusive_disjunction_list_1(),
+            40 => self.conjunction(&children[0], &children[1]),
+            41 => self.conjunction_list_0(&children[0], &children[1], &children[2]),
+            42 => self.conjunction_list_1(),
+            43 => self.conjunction_op_0(&children[0]),
+            44 => self.conjunction_op_1(&children[0]),
+            45 => self.negation(&children[0], &children[1]),
+            46 => self.constant_0(&children[0]),
+            47 => self.constant_1(&children[0]),
+            48 => self.factor_0(&children[0]),
+            49 => self.factor_1(&children[0]),
+            50 => self.factor_2(&children[0]),
+            51 => self.factor_3(&children[0], &children[1], &children[2]),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {prod_num}"
             ))
@@ -16,7 +16,7 @@ use std::path::Path;
 use crate::raa_tt_grammar::RaaTtGrammar;
 use crate::raa_tt_grammar_trait::RaaTtGrammarAuto;
 
-pub const TERMINAL_NAMES: &[&str; 14] = &[
+pub const TERMINAL_NAMES: &[&str; 21] = &[
     /*  0 */ "EndOfInput",
     /*  1 */ "Newline",
     /*  2 */ "Whitespace",
@@ -24,13 +24,20 @@ pub const TERMINAL_NAMES: &[&str; 14] = &[
     /*  4 */ "BlockComment",
     /*  5 */ "Not",
     /*  6 */ "And",
-    /*  7 */ "Or",
-    /*  8 */ "Cond",
-    /*  9 */ "BiCond",
-    /* 10 */ "LPar",
-    /* 11 */ "RPar",
-    /* 12 */ "Var",
-    /* 13 */ "Error",
+    /*  7 */ "Nand",
+    /*  8 */ "Xor",
+    /*  9 */ "Or",
+    /* 10 */ "Nor",
+    /* 11 */ "Cond",
+    /* 12 */ "BiCond",
+    /* 13 */ "LPar",
+    /* 14 */ "RPar",
+    /* 15 */ "Comma",
+    /* 16 */ "Turnstile",
+    /* 17 */ "True",
+    /* 18 */ "False",
+    /* 19 */ "Var",
+    /* 20 */ "Error",
 ];
 
 scanner! {
@@ -39,15 +46,22 @@ scanner! {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
             token r"//.*(\r\n|\r|\n)?" => 3; // "LineComment"
-            token r"!" => 5; // "Not"
-            token r"\&" => 6; // "And"
-            token r"\|" => 7; // "Or"
-            token r"\->" => 8; // "Cond"
-            token r"<\->" => 9; // "BiCond"
-            token r"\(" => 10; // "LPar"
-            token r"\)" => 11; // "RPar"
-            token r"[a-z][_a-zA-Z0-9]*" => 12; // "Var"
-            token r"." => 13; // "Error"
+            token r"!|¬|~|not" => 5; // "Not"
+            token r"&|∧|\x2F\\|and" => 6; // "And"
+            token r"NAND|↑|nand" => 7; // "Nand"
+            token r"\^|⊕|xor" => 8; // "Xor"
+            token r"\||∨|\\\x2F|or" => 9; // "Or"
+            token r"NOR|↓|nor" => 10; // "Nor"
+            token r"->|→|=>" => 11; // "Cond"
+            token r"<->|↔|<=>" => 12; // "BiCond"
+            token r"\(" => 13; // "LPar"
+            token r"\)" => 14; // "RPar"
+            token r"," => 15; // "Comma"
+            token r"\|-|⊢" => 16; // "Turnstile"
+            token r"T|⊤|true" => 17; // "True"
+            token r"F|⊥|false" => 18; // "False"
+            token r"[a-z][_a-zA-Z0-9]*" => 19; // "Var"
+            token r"." => 20; // "Error"
         }
     }
 }
@@ -55,352 +69,681 @@ scanner! {
 const MAX_K: usize = 1;
 pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
 
-pub const NON_TERMINALS: &[&str; 20] = &[
+pub const NON_TERMINALS: &[&str; 37] = &[
     /*  0 */ "And",
-    /*  1 */ "BiCond",
-    /*  2 */ "Biconditional",
-    /*  3 */ "BiconditionalList",
-    /*  4 */ "Cond",
-    /*  5 */ "Conditional",
-    /*  6 */ "ConditionalList",
-    /*  7 */ "Conjunction",
-    /*  8 */ "ConjunctionList",
-    /*  9 */ "Disjunction",
-    /* 10 */ "DisjunctionList",
-    /* 11 */ "Factor",
-    /* 12 */ "LPar",
-    /* 13 */ "Negation",
-    /* 14 */ "Not",
-    /* 15 */ "Or",
-    /* 16 */ "RPar",
-    /* 17 */ "RaaTt",
-    /* 18 */ "RaaTtList",
-    /* 19 */ "Var",
+    /*  1 */ "Argument",
+    /*  2 */ "ArgumentList",
+    /*  3 */ "BiCond",
+    /*  4 */ "Biconditional",
+    /*  5 */ "BiconditionalList",
+    /*  6 */ "Comma",
+    /*  7 */ "Cond",
+    /*  8 */ "Conditional",
+    /*  9 */ "ConditionalList",
+    /* 10 */ "Conjunction",
+    /* 11 */ "ConjunctionList",
+    /* 12 */ "ConjunctionOp",
+    /* 13 */ "Constant",
+    /* 14 */ "Disjunction",
+    /* 15 */ "DisjunctionList",
+    /* 16 */ "DisjunctionOp",
+    /* 17 */ "ExclusiveDisjunction",
+    /* 18 */ "ExclusiveDisjunctionList",
+    /* 19 */ "Factor",
+    /* 20 */ "False",
+    /* 21 */ "LPar",
+    /* 22 */ "Nand",
+    /* 23 */ "Negation",
+    /* 24 */ "Nor",
+    /* 25 */ "Not",
+    /* 26 */ "Or",
+    /* 27 */ "RPar",
+    /* 28 */ "RaaTt",
+    /* 29 */ "RaaTtList",
+    /* 30 */ "RaaTtOpt",
+    /* 31 */ "Theorems",
+    /* 32 */ "TheoremsList",
+    /* 33 */ "True",
+    /* 34 */ "Turnstile",
+    /* 35 */ "Var",
+    /* 36 */ "Xor",
 ];
 
-pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 20] = &[
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 37] = &[
     /* 0 - "And" */
     LookaheadDFA {
         prod0: 1,
         transitions: &[],
         k: 0,
     },
-    /* 1 - "BiCond" */
+    /* 1 - "Argument" */
     LookaheadDFA {
-        prod0: 4,
+        prod0: 23,
         transitions: &[],
         k: 0,
     },
-    /* 2 - "Biconditional" */
+    /* 2 - "ArgumentList" */
     LookaheadDFA {
-        prod0: 11,
+        prod0: -1,
+        transitions: &[Trans(0, 15, 1, 24), Trans(0, 16, 2, 25)],
+        k: 1,
+    },
+    /* 3 - "BiCond" */
+    LookaheadDFA {
+        prod0: 7,
         transitions: &[],
         k: 0,
     },
-    /* 3 - "BiconditionalList" */
+    /* 4 - "Biconditional" */
+    LookaheadDFA {
+        prod0: 26,
+        transitions: &[],
+        k: 0,
+    },
+    /* 5 - "BiconditionalList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 0, 2, 13),
-            Trans(0, 5, 2, 13),
-            Trans(0, 9, 1, 12),
-            Trans(0, 10, 2, 13),
-            Trans(0, 11, 2, 13),
-            Trans(0, 12, 2, 13),
+            Trans(0, 0, 2, 28),
+            Trans(0, 5, 2, 28),
+            Trans(0, 12, 1, 27),
+            Trans(0, 13, 2, 28),
+            Trans(0, 14, 2, 28),
+            Trans(0, 15, 2, 28),
+            Trans(0, 16, 2, 28),
+            Trans(0, 17, 2, 28),
+            Trans(0, 18, 2, 28),
+            Trans(0, 19, 2, 28),
         ],
         k: 1,
     },
-    /* 4 - "Cond" */
+    /* 6 - "Comma" */
     LookaheadDFA {
-        prod0: 3,
+        prod0: 10,
         transitions: &[],
         k: 0,
     },
-    /* 5 - "Conditional" */
+    /* 7 - "Cond" */
     LookaheadDFA {
-        prod0: 14,
+        prod0: 6,
+        transitions: &[],
+        k: 0,
+    },
+    /* 8 - "Conditional" */
+    LookaheadDFA {
+        prod0: 29,
         transitions: &[],
         k: 0,
     },
-    /* 6 - "ConditionalList" */
+    /* 9 - "ConditionalList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 0, 2, 16),
-            Trans(0, 5, 2, 16),
-            Trans(0, 8, 1, 15),
-            Trans(0, 9, 2, 16),
-            Trans(0, 10, 2, 16),
-            Trans(0, 11, 2, 16),
-            Trans(0, 12, 2, 16),
+            Trans(0, 0, 2, 31),
+            Trans(0, 5, 2, 31),
+            Trans(0, 11, 1, 30),
+            Trans(0, 12, 2, 31),
+            Trans(0, 13, 2, 31),
+            Trans(0, 14, 2, 31),
+            Trans(0, 15, 2, 31),
+            Trans(0, 16, 2, 31),
+            Trans(0, 17, 2, 31),
+            Trans(0, 18, 2, 31),
+            Trans(0, 19, 2, 31),
         ],
         k: 1,
     },
-    /* 7 - "Conjunction" */
+    /* 10 - "Conjunction" */
     LookaheadDFA {
-        prod0: 20,
+        prod0: 40,
         transitions: &[],
         k: 0,
     },
-    /* 8 - "ConjunctionList" */
+    /* 11 - "ConjunctionList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 0, 2, 22),
-            Trans(0, 5, 2, 22),
-            Trans(0, 6, 1, 21),
-            Trans(0, 7, 2, 22),
-            Trans(0, 8, 2, 22),
-            Trans(0, 9, 2, 22),
-            Trans(0, 10, 2, 22),
-            Trans(0, 11, 2, 22),
-            Trans(0, 12, 2, 22),
+            Trans(0, 0, 2, 42),
+            Trans(0, 5, 2, 42),
+            Trans(0, 6, 1, 41),
+            Trans(0, 7, 1, 41),
+            Trans(0, 8, 2, 42),
+            Trans(0, 9, 2, 42),
+            Trans(0, 10, 2, 42),
+            Trans(0, 11, 2, 42),
+            Trans(0, 12, 2, 42),
+            Trans(0, 13, 2, 42),
+            Trans(0, 14, 2, 42),
+            Trans(0, 15, 2, 42),
+            Trans(0, 16, 2, 42),
+            Trans(0, 17, 2, 42),
+            Trans(0, 18, 2, 42),
+            Trans(0, 19, 2, 42),
         ],
         k: 1,
     },
-    /* 9 - "Disjunction" */
+    /* 12 - "ConjunctionOp" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 6, 1, 43), Trans(0, 7, 2, 44)],
+        k: 1,
+    },
+    /* 13 - "Constant" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 17, 1, 46), Trans(0, 18, 2, 47)],
+        k: 1,
+    },
+    /* 14 - "Disjunction" */
     LookaheadDFA {
-        prod0: 17,
+        prod0: 32,
         transitions: &[],
         k: 0,
     },
-    /* 10 - "DisjunctionList" */
+    /* 15 - "DisjunctionList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 0, 2, 19),
-            Trans(0, 5, 2, 19),
-            Trans(0, 7, 1, 18),
-            Trans(0, 8, 2, 19),
-            Trans(0, 9, 2, 19),
-            Trans(0, 10, 2, 19),
-            Trans(0, 11, 2, 19),
-            Trans(0, 12, 2, 19),
+            Trans(0, 0, 2, 34),
+            Trans(0, 5, 2, 34),
+            Trans(0, 9, 1, 33),
+            Trans(0, 10, 1, 33),
+            Trans(0, 11, 2, 34),
+            Trans(0, 12, 2, 34),
+            Trans(0, 13, 2, 34),
+            Trans(0, 14, 2, 34),
+            Trans(0, 15, 2, 34),
+            Trans(0, 16, 2, 34),
+            Trans(0, 17, 2, 34),
+            Trans(0, 18, 2, 34),
+            Trans(0, 19, 2, 34),
         ],
         k: 1,
     },
-    /* 11 - "Factor" */
+    /* 16 - "DisjunctionOp" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 25), Trans(0, 10, 3, 26), Trans(0, 12, 1, 24)],
+        transitions: &[Trans(0, 9, 1, 35), Trans(0, 10, 2, 36)],
         k: 1,
     },
-    /* 12 - "LPar" */
+    /* 17 - "ExclusiveDisjunction" */
     LookaheadDFA {
-        prod0: 5,
+        prod0: 37,
         transitions: &[],
         k: 0,
     },
-    /* 13 - "Negation" */
+    /* 18 - "ExclusiveDisjunctionList" */
     LookaheadDFA {
-        prod0: 23,
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 39),
+            Trans(0, 5, 2, 39),
+            Trans(0, 8, 1, 38),
+            Trans(0, 9, 2, 39),
+            Trans(0, 10, 2, 39),
+            Trans(0, 11, 2, 39),
+            Trans(0, 12, 2, 39),
+            Trans(0, 13, 2, 39),
+            Trans(0, 14, 2, 39),
+            Trans(0, 15, 2, 39),
+            Trans(0, 16, 2, 39),
+            Trans(0, 17, 2, 39),
+            Trans(0, 18, 2, 39),
+            Trans(0, 19, 2, 39),
+        ],
+        k: 1,
+    },
+    /* 19 - "Factor" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 5, 3, 50),
+            Trans(0, 13, 4, 51),
+            Trans(0, 17, 1, 48),
+            Trans(0, 18, 1, 48),
+            Trans(0, 19, 2, 49),
+        ],
+        k: 1,
+    },
+    /* 20 - "False" */
+    LookaheadDFA {
+        prod0: 13,
         transitions: &[],
         k: 0,
     },
-    /* 14 - "Not" */
+    /* 21 - "LPar" */
     LookaheadDFA {
-        prod0: 0,
+        prod0: 8,
         transitions: &[],
         k: 0,
     },
-    /* 15 - "Or" */
+    /* 22 - "Nand" */
     LookaheadDFA {
         prod0: 2,
         transitions: &[],
         k: 0,
     },
-    /* 16 - "RPar" */
+    /* 23 - "Negation" */
     LookaheadDFA {
-        prod0: 6,
+        prod0: 45,
         transitions: &[],
         k: 0,
     },
-    /* 17 - "RaaTt" */
+    /* 24 - "Nor" */
     LookaheadDFA {
-        prod0: 8,
+        prod0: 5,
+        transitions: &[],
+        k: 0,
+    },
+    /* 25 - "Not" */
+    LookaheadDFA {
+        prod0: 0,
+        transitions: &[],
+        k: 0,
+    },
+    /* 26 - "Or" */
+    LookaheadDFA {
+        prod0: 4,
+        transitions: &[],
+        k: 0,
+    },
+    /* 27 - "RPar" */
+    LookaheadDFA {
+        prod0: 9,
         transitions: &[],
         k: 0,
     },
-    /* 18 - "RaaTtList" */
+    /* 28 - "RaaTt" */
+    LookaheadDFA {
+        prod0: 15,
+        transitions: &[],
+        k: 0,
+    },
+    /* 29 - "RaaTtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 0, 2, 10),
-            Trans(0, 5, 1, 9),
-            Trans(0, 10, 1, 9),
-            Trans(0, 12, 1, 9),
+            Trans(0, 0, 2, 17),
+            Trans(0, 5, 1, 16),
+            Trans(0, 13, 1, 16),
+            Trans(0, 17, 1, 16),
+            Trans(0, 18, 1, 16),
+            Trans(0, 19, 1, 16),
         ],
         k: 1,
     },
-    /* 19 - "Var" */
+    /* 30 - "RaaTtOpt" */
     LookaheadDFA {
-        prod0: 7,
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 19),
+            Trans(0, 5, 2, 19),
+            Trans(0, 13, 2, 19),
+            Trans(0, 15, 1, 18),
+            Trans(0, 16, 1, 18),
+            Trans(0, 17, 2, 19),
+            Trans(0, 18, 2, 19),
+            Trans(0, 19, 2, 19),
+        ],
+        k: 1,
+    },
+    /* 31 - "Theorems" */
+    LookaheadDFA {
+        prod0: 20,
+        transitions: &[],
+        k: 0,
+    },
+    /* 32 - "TheoremsList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 22),
+            Trans(0, 5, 2, 22),
+            Trans(0, 13, 2, 22),
+            Trans(0, 16, 1, 21),
+            Trans(0, 17, 2, 22),
+            Trans(0, 18, 2, 22),
+            Trans(0, 19, 2, 22),
+        ],
+        k: 1,
+    },
+    /* 33 - "True" */
+    LookaheadDFA {
+        prod0: 12,
+        transitions: &[],
+        k: 0,
+    },
+    /* 34 - "Turnstile" */
+    LookaheadDFA {
+        prod0: 11,
+        transitions: &[],
+        k: 0,
+    },
+    /* 35 - "Var" */
+    LookaheadDFA {
+        prod0: 14,
+        transitions: &[],
+        k: 0,
+    },
+    /* 36 - "Xor" */
+    LookaheadDFA {
+        prod0: 3,
         transitions: &[],
         k: 0,
     },
 ];
 
-pub const PRODUCTIONS: &[Production; 27] = &[
-    // 0 - Not: '!';
+pub const PRODUCTIONS: &[Production; 52] = &[
+    // 0 - Not: /!|¬|~|not/;
     Production {
-        lhs: 14,
+        lhs: 25,
         production: &[ParseType::T(5)],
         is_push_production: false,
     },
-    // 1 - And: '&';
+    // 1 - And: /&|∧|\x2F\\|and/;
     Production {
         lhs: 0,
         production: &[ParseType::T(6)],
         is_push_production: false,
     },
-    // 2 - Or: '|';
+    // 2 - Nand: /NAND|↑|nand/;
     Production {
-        lhs: 15,
+        lhs: 22,
         production: &[ParseType::T(7)],
         is_push_production: false,
     },
-    // 3 - Cond: '->';
+    // 3 - Xor: /\^|⊕|xor/;
     Production {
-        lhs: 4,
+        lhs: 36,
         production: &[ParseType::T(8)],
         is_push_production: false,
     },
-    // 4 - BiCond: '<->';
+    // 4 - Or: /\||∨|\\\x2F|or/;
     Production {
-        lhs: 1,
+        lhs: 26,
         production: &[ParseType::T(9)],
         is_push_production: false,
     },
-    // 5 - LPar: '(';
+    // 5 - Nor: /NOR|↓|nor/;
     Production {
-        lhs: 12,
+        lhs: 24,
         production: &[ParseType::T(10)],
         is_push_production: false,
     },
-    // 6 - RPar: ')';
+    // 6 - Cond: /->|→|=>/;
     Production {
-        lhs: 16,
+        lhs: 7,
         production: &[ParseType::T(11)],
         is_push_production: false,
     },
-    // 7 - Var: /[a-z][_a-zA-Z0-9]*/;
+    // 7 - BiCond: /<->|↔|<=>/;
     Production {
-        lhs: 19,
+        lhs: 3,
         production: &[ParseType::T(12)],
         is_push_production: false,
     },
-    // 8 - RaaTt: RaaTtList /* Vec */;
+    // 8 - LPar: '(';
     Production {
-        lhs: 17,
-        production: &[ParseType::N(18)],
+        lhs: 21,
+        production: &[ParseType::T(13)],
         is_push_production: false,
     },
-    // 9 - RaaTtList: Biconditional RaaTtList;
+    // 9 - RPar: ')';
     Production {
-        lhs: 18,
-        production: &[ParseType::N(18), ParseType::N(2)],
+        lhs: 27,
+        production: &[ParseType::T(14)],
+        is_push_production: false,
+    },
+    // 10 - Comma: ',';
+    Production {
+        lhs: 6,
+        production: &[ParseType::T(15)],
+        is_push_production: false,
+    },
+    // 11 - Turnstile: /\|-|⊢/;
+    Production {
+        lhs: 34,
+        production: &[ParseType::T(16)],
+        is_push_production: false,
+    },
+    // 12 - True: /T|⊤|true/;
+    Production {
+        lhs: 33,
+        production: &[ParseType::T(17)],
+        is_push_production: false,
+    },
+    // 13 - False: /F|⊥|false/;
+    Production {
+        lhs: 20,
+        production: &[ParseType::T(18)],
+        is_push_production: false,
+    },
+    // 14 - Var: /[a-z][_a-zA-Z0-9]*/;
+    Production {
+        lhs: 35,
+        production: &[ParseType::T(19)],
+        is_push_production: false,
+    },
+    // 15 - RaaTt: Theorems RaaTtList /* Vec */;
+    Production {
+        lhs: 28,
+        production: &[ParseType::N(29), ParseType::N(31)],
+        is_push_production: false,
+    },
+    // 16 - RaaTtList: Biconditional RaaTtOpt /* Option */ RaaTtList;
+    Production {
+        lhs: 29,
+        production: &[ParseType::N(29), ParseType::N(30), ParseType::N(4)],
         is_push_production: true,
     },
-    // 10 - RaaTtList: ;
+    // 17 - RaaTtList: ;
     Production {
-        lhs: 18,
+        lhs: 29,
         production: &[],
         is_push_production: false,
     },
-    // 11 - Biconditional: Conditional BiconditionalList /* Vec */;
+    // 18 - RaaTtOpt: Argument Theorems;
     Production {
-        lhs: 2,
-        production: &[ParseType::N(3), ParseType::N(5)],
+        lhs: 30,
+        production: &[ParseType::N(31), ParseType::N(1)],
         is_push_production: false,
     },
-    // 12 - BiconditionalList: BiCond^ /* Clipped */ Conditional BiconditionalList;
+    // 19 - RaaTtOpt: ;
     Production {
-        lhs: 3,
-        production: &[ParseType::N(3), ParseType::N(5), ParseType::N(1)],
+        lhs: 30,
+        production: &[],
+        is_push_production: false,
+    },
+    // 20 - Theorems: TheoremsList /* Vec */;
+    Production {
+        lhs: 31,
+        production: &[ParseType::N(32)],
+        is_push_production: false,
+    },
+    // 21 - TheoremsList: Turnstile^ /* Clipped */ Biconditional TheoremsList;
+    Production {
+        lhs: 32,
+        production: &[ParseType::N(32), ParseType::N(4), ParseType::N(34)],
         is_push_production: true,
     },
-    // 13 - BiconditionalList: ;
+    // 22 - TheoremsList: ;
     Production {
-        lhs: 3,
+        lhs: 32,
         production: &[],
         is_push_production: false,
     },
-    // 14 - Conditional: Disjunction ConditionalList /* Vec */;
+    // 23 - Argument: ArgumentList /* Vec */ Turnstile^ /* Clipped */ Biconditional;
     Production {
-        lhs: 5,
-        production: &[ParseType::N(6), ParseType::N(9)],
+        lhs: 1,
+        production: &[ParseType::N(4), ParseType::N(34), ParseType::N(2)],
         is_push_production: false,
     },
-    // 15 - ConditionalList: Cond^ /* Clipped */ Disjunction ConditionalList;
+    // 24 - ArgumentList: Comma^ /* Clipped */ Biconditional ArgumentList;
     Production {
-        lhs: 6,
-        production: &[ParseType::N(6), ParseType::N(9), ParseType::N(4)],
+        lhs: 2,
+        production: &[ParseType::N(2), ParseType::N(4), ParseType::N(6)],
         is_push_production: true,
     },
-    // 16 - ConditionalList: ;
+    // 25 - ArgumentList: ;
     Production {
-        lhs: 6,
+        lhs: 2,
+        production: &[],
+        is_push_production: false,
+    },
+    // 26 - Biconditional: Conditional BiconditionalList /* Vec */;
+    Production {
+        lhs: 4,
+        production: &[ParseType::N(5), ParseType::N(8)],
+        is_push_production: false,
+    },
+    // 27 - BiconditionalList: BiCond^ /* Clipped */ Conditional BiconditionalList;
+    Production {
+        lhs: 5,
+        production: &[ParseType::N(5), ParseType::N(8), ParseType::N(3)],
+        is_push_production: true,
+    },
+    // 28 - BiconditionalList: ;
+    Production {
+        lhs: 5,
         production: &[],
         is_push_production: false,
     },
-    // 17 - Disjunction: Conjunction DisjunctionList /* Vec */;
+    // 29 - Conditional: Disjunction ConditionalList /* Vec */;
+    Production {
+        lhs: 8,
+        production: &[ParseType::N(9), ParseType::N(14)],
+        is_push_production: false,
+    },
+    // 30 - ConditionalList: Cond Disjunction ConditionalList;
     Production {
         lhs: 9,
-        production: &[ParseType::N(10), ParseType::N(7)],
+        production: &[ParseType::N(9), ParseType::N(14), ParseType::N(7)],
+        is_push_production: true,
+    },
+    // 31 - ConditionalList: ;
+    Production {
+        lhs: 9,
+        production: &[],
         is_push_production: false,
     },
-    // 18 - DisjunctionList: Or^ /* Clipped */ Conjunction DisjunctionList;
+    // 32 - Disjunction: ExclusiveDisjunction DisjunctionList /* Vec */;
     Production {
-        lhs: 10,
-        production: &[ParseType::N(10), ParseType::N(7), ParseType::N(15)],
+        lhs: 14,
+        production: &[ParseType::N(15), ParseType::N(17)],
+        is_push_production: false,
+    },
+    // 33 - DisjunctionList: DisjunctionOp ExclusiveDisjunction DisjunctionList;
+    Production {
+        lhs: 15,
+        production: &[ParseType::N(15), ParseType::N(17), ParseType::N(16)],
         is_push_production: true,
     },
-    // 19 - DisjunctionList: ;
+    // 34 - DisjunctionList: ;
     Production {
-        lhs: 10,
+        lhs: 15,
         production: &[],
         is_push_production: false,
     },
-    // 20 - Conjunction: Factor ConjunctionList /* Vec */;
+    // 35 - DisjunctionOp: Or;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(8), ParseType::N(11)],
+        lhs: 16,
+        production: &[ParseType::N(26)],
         is_push_production: false,
     },
-    // 21 - ConjunctionList: And^ /* Clipped */ Factor ConjunctionList;
+    // 36 - DisjunctionOp: Nor;
     Production {
-        lhs: 8,
-        production: &[ParseType::N(8), ParseType::N(11), ParseType::N(0)],
+        lhs: 16,
+        production: &[ParseType::N(24)],
+        is_push_production: false,
+    },
+    // 37 - ExclusiveDisjunction: Conjunction ExclusiveDisjunctionList /* Vec */;
+    Production {
+        lhs: 17,
+        production: &[ParseType::N(18), ParseType::N(10)],
+        is_push_production: false,
+    },
+    // 38 - ExclusiveDisjunctionList: Xor^ /* Clipped */ Conjunction ExclusiveDisjunctionList;
+    Production {
+        lhs: 18,
+        production: &[ParseType::N(18), ParseType::N(10), ParseType::N(36)],
         is_push_production: true,
     },
-    // 22 - ConjunctionList: ;
+    // 39 - ExclusiveDisjunctionList: ;
     Production {
-        lhs: 8,
+        lhs: 18,
         production: &[],
         is_push_production: false,
     },
-    // 23 - Negation: Not^ /* Clipped */ Factor;
+    // 40 - Conjunction: Factor ConjunctionList /* Vec */;
     Production {
-        lhs: 13,
-        production: &[ParseType::N(11), ParseType::N(14)],
+        lhs: 10,
+        production: &[ParseType::N(11), ParseType::N(19)],
         is_push_production: false,
     },
-    // 24 - Factor: Var;
+    // 41 - ConjunctionList: ConjunctionOp Factor ConjunctionList;
     Production {
         lhs: 11,
-        production: &[ParseType::N(19)],
-        is_push_production: false,
+        production: &[ParseType::N(11), ParseType::N(19), ParseType::N(12)],
+        is_push_production: true,
     },
-    // 25 - Factor: Negation;
+    // 42 - ConjunctionList: ;
     Production {
         lhs: 11,
+        production: &[],
+        is_push_production: false,
+    },
+    // 43 - ConjunctionOp: And;
+    Production {
+        lhs: 12,
+        production: &[ParseType::N(0)],
+        is_push_production: false,
+    },
+    // 44 - ConjunctionOp: Nand;
+    Production {
+        lhs: 12,
+        production: &[ParseType::N(22)],
+        is_push_production: false,
+    },
+    // 45 - Negation: Not^ /* Clipped */ Factor;
+    Production {
+        lhs: 23,
+        production: &[ParseType::N(19), ParseType::N(25)],
+        is_push_production: false,
+    },
+    // 46 - Constant: True;
+    Production {
+        lhs: 13,
+        production: &[ParseType::N(33)],
+        is_push_production: false,
+    },
+    // 47 - Constant: False;
+    Production {
+        lhs: 13,
+        production: &[ParseType::N(20)],
+        is_push_production: false,
+    },
+    // 48 - Factor: Constant;
+    Production {
+        lhs: 19,
         production: &[ParseType::N(13)],
         is_push_production: false,
     },
-    // 26 - Factor: LPar^ /* Clipped */ Biconditional RPar^ /* Clipped */;
+    // 49 - Factor: Var;
     Production {
-        lhs: 11,
-        production: &[ParseType::N(16), ParseType::N(2), ParseType::N(12)],
+        lhs: 19,
+        production: &[ParseType::N(35)],
+        is_push_production: false,
+    },
+    // 50 - Factor: Negation;
+    Production {
+        lhs: 19,
+        production: &[ParseType::N(23)],
+        is_push_production: false,
+    },
+    // 51 - Factor: LPar^ /* Clipped */ Biconditional RPar^ /* Clipped */;
+    Production {
+        lhs: 19,
+        production: &[ParseType::N(27), ParseType::N(4), ParseType::N(21)],
         is_push_production: false,
     },
 ];
@@ -433,7 +776,7 @@ where
 {
     use raa_tt_grammar_scanner::RaaTtGrammarScanner;
     let mut llk_parser = LLKParser::new(
-        17,
+        28,
         LOOKAHEAD_AUTOMATA,
         PRODUCTIONS,
         TERMINAL_NAMES,
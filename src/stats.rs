@@ -0,0 +1,116 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Error, Formatter},
+    time::Duration,
+};
+
+use crate::tableau::Rule;
+
+/// Statistics about the work the [`Prover`] has done
+///
+/// If a proof needs more than one truth tree, the statistics of all trees are summed up. The
+/// maximum depth is the one of the deepest tree.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{
+///     prover::{Prover, ProveResult},
+///     proposition::Proposition,
+///     disjunction::Disjunction,
+///     negation::Negation,
+///     tableau::Rule,
+/// };
+///
+/// // P ∨ ¬P
+/// let proposition = Proposition::Disjunction(Disjunction {
+///     left: Box::new("P".into()),
+///     right: Box::new(Proposition::Negation(Negation {
+///         inner: Box::new("P".into()),
+///     })),
+/// });
+///
+/// let witness = Prover::new().prove_with_witness(&proposition).unwrap();
+/// assert_eq!(witness.result, ProveResult::Proven);
+/// // ¬(P ∨ ¬P), ¬P, ¬¬P
+/// assert_eq!(witness.stats.nodes, 3);
+/// assert_eq!(witness.stats.branches_closed, 1);
+/// assert_eq!(witness.stats.rule_applications[&Rule::NegatedDisjunction], 1);
+/// assert!(
+///     witness
+///         .stats
+///         .to_string()
+///         .starts_with("3 nodes, 1 step, 1 branch opened, 1 branch closed")
+/// );
+/// ```
+///
+/// [`Prover`]: crate::prover::Prover
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProofStats {
+    /// The number of nodes of all truth trees
+    pub nodes: usize,
+
+    /// The number of nodes taken from the agenda of the truth trees
+    pub steps: usize,
+
+    /// The number of branches, including the initial branch of each tree and all branches created
+    /// by branching rules
+    pub branches_opened: usize,
+
+    /// The number of branches that have been closed by a contradiction
+    pub branches_closed: usize,
+
+    /// The number of nodes on the longest branch
+    pub max_depth: usize,
+
+    /// The number of decomposed nodes per rule
+    pub rule_applications: BTreeMap<Rule, usize>,
+
+    /// The time spent
+    pub elapsed: Duration,
+}
+
+impl ProofStats {
+    // Adds the statistics of another truth tree. The elapsed time is left untouched.
+    pub(crate) fn add(&mut self, other: &ProofStats) {
+        self.nodes += other.nodes;
+        self.steps += other.steps;
+        self.branches_opened += other.branches_opened;
+        self.branches_closed += other.branches_closed;
+        self.max_depth = self.max_depth.max(other.max_depth);
+        for (rule, count) in &other.rule_applications {
+            *self.rule_applications.entry(*rule).or_default() += count;
+        }
+    }
+}
+
+impl Display for ProofStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        writeln!(
+            f,
+            "{}, {}, {} opened, {} closed, maximum depth {}, {:.3} milliseconds",
+            counted(self.nodes, "node", "nodes"),
+            counted(self.steps, "step", "steps"),
+            counted(self.branches_opened, "branch", "branches"),
+            counted(self.branches_closed, "branch", "branches"),
+            self.max_depth,
+            self.elapsed.as_secs_f64() * 1000.0
+        )?;
+        if !self.rule_applications.is_empty() {
+            write!(f, "Rule applications: ")?;
+            for (i, (rule, count)) in self.rule_applications.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{} {count}", rule.name())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// Returns the count followed by the singular or plural noun.
+fn counted(count: usize, singular: &str, plural: &str) -> String {
+    format!("{count} {}", if count == 1 { singular } else { plural })
+}
@@ -0,0 +1,173 @@
+use std::{
+    fmt::{Debug, Error, Formatter},
+    sync::Arc,
+};
+
+use petgraph::graph::NodeIndex;
+
+use crate::{
+    arena::{PropId, PropositionArena},
+    proposition::Proposition,
+    prover::{Expansion, OpenBranch},
+    tableau::Rule,
+};
+
+/// The priority function of a user supplied [`ExpansionStrategy`]
+pub type PriorityFn = dyn Fn(&Candidate<'_>) -> i64 + Send + Sync;
+
+/// The order in which the [`Prover`] decomposes the nodes of a truth tree
+///
+/// Each strategy assigns a priority to the unprocessed nodes. The node with the smallest priority
+/// is decomposed next, ties are broken by the order in which the nodes were added to the tree.
+/// The strategy doesn't change the result of a proof, but it can change the size of the truth
+/// tree considerably.
+///
+/// # Examples
+///
+/// ```
+/// use raa_tt::{
+///     prover::{Prover, ProveResult},
+///     proposition::Proposition,
+///     strategy::ExpansionStrategy,
+/// };
+///
+/// // Prefer nodes that lie on few open branches
+/// let strategy = ExpansionStrategy::custom(|candidate| candidate.open_branches() as i64);
+/// let prover = Prover::with_strategy(strategy);
+/// assert_eq!(prover.prove(&"p".into()).unwrap(), ProveResult::Contingent);
+/// ```
+///
+/// [`Prover`]: crate::prover::Prover
+#[derive(Clone, Default)]
+pub enum ExpansionStrategy {
+    /// Nodes are decomposed in the order they were added to the tree
+    #[default]
+    InOrder,
+
+    /// Nodes that don't split branches (α-rules) are decomposed before nodes that do (β-rules)
+    AlphaFirst,
+
+    /// The node with the smallest proposition is decomposed first
+    SmallestFirst,
+
+    /// The node whose decomposition leaves the fewest open branches is decomposed first.
+    /// Branches that close immediately because of the decomposition aren't counted.
+    MostConstrainedFirst,
+
+    /// A user supplied priority function
+    Custom(Arc<PriorityFn>),
+}
+
+impl ExpansionStrategy {
+    /// Creates a strategy from a user supplied priority function.
+    ///
+    /// Nodes with smaller priorities are decomposed first.
+    pub fn custom(priority: impl Fn(&Candidate<'_>) -> i64 + Send + Sync + 'static) -> Self {
+        ExpansionStrategy::Custom(Arc::new(priority))
+    }
+
+    /// Returns the name of the strategy.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExpansionStrategy::InOrder => "in-order",
+            ExpansionStrategy::AlphaFirst => "alpha-first",
+            ExpansionStrategy::SmallestFirst => "smallest-first",
+            ExpansionStrategy::MostConstrainedFirst => "most-constrained-first",
+            ExpansionStrategy::Custom(_) => "custom",
+        }
+    }
+
+    // Returns true if the priority of a node doesn't change while the tree is developed.
+    pub(crate) fn is_static(&self) -> bool {
+        matches!(
+            self,
+            ExpansionStrategy::InOrder
+                | ExpansionStrategy::AlphaFirst
+                | ExpansionStrategy::SmallestFirst
+        )
+    }
+
+    pub(crate) fn priority(&self, candidate: &Candidate<'_>) -> i64 {
+        match self {
+            ExpansionStrategy::InOrder => 0,
+            ExpansionStrategy::AlphaFirst => candidate.is_branching() as i64,
+            ExpansionStrategy::SmallestFirst => candidate.size() as i64,
+            ExpansionStrategy::MostConstrainedFirst => {
+                candidate.surviving_branches() as i64 - candidate.open_branches() as i64
+            }
+            ExpansionStrategy::Custom(priority) => priority(candidate),
+        }
+    }
+}
+
+impl Debug for ExpansionStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An unprocessed node of a truth tree that is offered to an [`ExpansionStrategy`]
+pub struct Candidate<'a> {
+    pub(crate) node: NodeIndex,
+    pub(crate) proposition: PropId,
+    pub(crate) arena: &'a PropositionArena,
+    pub(crate) expansion: &'a Expansion,
+    pub(crate) open_branches: &'a [OpenBranch],
+}
+
+impl Candidate<'_> {
+    /// Returns the id of the node. It equals the id of the corresponding [`TableauNode`].
+    ///
+    /// [`TableauNode`]: crate::tableau::TableauNode
+    pub fn id(&self) -> usize {
+        self.node.index()
+    }
+
+    /// Returns the proposition of the node.
+    pub fn proposition(&self) -> Proposition {
+        self.arena.to_proposition(self.proposition)
+    }
+
+    /// Returns the number of atoms and connectives of the proposition.
+    pub fn size(&self) -> usize {
+        self.arena.size(self.proposition)
+    }
+
+    /// Returns the rule that decomposes the node, if any.
+    pub fn rule(&self) -> Option<Rule> {
+        self.expansion.rule
+    }
+
+    /// Returns true if the decomposition splits the branches of the node.
+    pub fn is_branching(&self) -> bool {
+        self.expansion.rule.is_some_and(|r| r.is_branching())
+    }
+
+    /// Returns the number of open branches the node lies on.
+    pub fn open_branches(&self) -> usize {
+        self.branches().count()
+    }
+
+    /// Returns the number of open branches that remain after the node has been decomposed.
+    pub fn surviving_branches(&self) -> usize {
+        let sides = if self.is_branching() {
+            vec![&self.expansion.left, &self.expansion.right]
+        } else {
+            vec![&self.expansion.left]
+        };
+        self.branches()
+            .map(|branch| {
+                sides
+                    .iter()
+                    .filter(|side| !side.iter().any(|p| branch.contradicts(*p, self.arena)))
+                    .count()
+            })
+            .sum()
+    }
+
+    fn branches(&self) -> impl Iterator<Item = &OpenBranch> {
+        self.open_branches
+            .iter()
+            .filter(|b| b.nodes.contains(&self.node))
+    }
+}
@@ -4,11 +4,17 @@ use std::{
 };
 
 use crate::{
+    alternative_denial::AlternativeDenial,
+    arena::{PropId, PropositionArena},
+    assignment::Assignment,
     bi_implication::BiImplication,
     conjunction::Conjunction,
     disjunction::Disjunction,
     errors::{RaaError, Result},
+    exclusive_disjunction::ExclusiveDisjunction,
     implication::Implication,
+    joint_denial::JointDenial,
+    models::Models,
     negation::Negation,
     proposition::Proposition,
     truth_table::TruthTable,
@@ -34,9 +40,40 @@ const MAX_VARIABLES_IN_TRUTH_TABLE: usize = 16;
 const VARIABLE_WARNING_THRESHOLD: usize = 12;
 
 impl Proposition {
-    fn calculate_value(&self, vars: &BTreeMap<String, bool>) -> Result<bool> {
+    /// Evaluates the proposition under the given assignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{assignment::Assignment, proposition::Proposition, negation::Negation};
+    ///
+    /// let proposition = Proposition::Negation(Negation { inner: Box::new("p".into()) });
+    /// let mut assignment = Assignment::new();
+    /// assignment.set("p", true);
+    /// assert!(!proposition.evaluate(&assignment).unwrap());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// - [`RaaError::UndefinedVariable`]: Returned when a variable of the proposition is not
+    ///   assigned
+    /// - [`RaaError::VoidExpression`]: Returned when the proposition contains invalid expressions
+    ///
+    /// [`RaaError::UndefinedVariable`]: crate::errors::RaaError::UndefinedVariable
+    /// [`RaaError::VoidExpression`]: crate::errors::RaaError::VoidExpression
+    pub fn evaluate(&self, assignment: &Assignment) -> Result<bool> {
+        self.calculate_value(assignment.values()).map_err(|e| *e)
+    }
+
+    // The error is boxed to keep the frames of the recursion small for deeply nested
+    // propositions.
+    fn calculate_value(
+        &self,
+        vars: &BTreeMap<String, bool>,
+    ) -> std::result::Result<bool, Box<RaaError>> {
         let result = match self {
             Proposition::Void => Err(RaaError::VoidExpression)?,
+            Proposition::Constant(value) => *value,
             Proposition::Atom(a) => vars
                 .get(a)
                 .ok_or(RaaError::UndefinedVariable { name: a.to_owned() })
@@ -54,6 +91,15 @@ impl Proposition {
             Proposition::Conjunction(Conjunction { left, right }) => {
                 left.calculate_value(vars)? && right.calculate_value(vars)?
             }
+            Proposition::ExclusiveDisjunction(ExclusiveDisjunction { left, right }) => {
+                left.calculate_value(vars)? != right.calculate_value(vars)?
+            }
+            Proposition::AlternativeDenial(AlternativeDenial { left, right }) => {
+                !(left.calculate_value(vars)? && right.calculate_value(vars)?)
+            }
+            Proposition::JointDenial(JointDenial { left, right }) => {
+                !(left.calculate_value(vars)? || right.calculate_value(vars)?)
+            }
         };
         Ok(result)
     }
@@ -61,8 +107,8 @@ impl Proposition {
 
 #[derive(Debug, Default)]
 pub struct TableGenerator {
-    // Variable set paired with values that change in every line
-    vars: RefCell<BTreeMap<String, bool>>,
+    // Variable set paired with their index into the values of the arena of the current table
+    vars: RefCell<BTreeMap<String, usize>>,
 }
 
 impl TableGenerator {
@@ -240,22 +286,25 @@ impl TableGenerator {
     ///
     /// - [`RaaError::TooManyVariables`]: Returned when the proposition contains more than 16 variables
     /// - [`RaaError::VoidExpression`]: Returned when the proposition contains invalid expressions
-    /// - [`RaaError::UndefinedVariable`]: Returned when variable evaluation fails (internal error)
     ///
     /// [`TruthTable`]: crate::truth_table::TruthTable
     /// [`RaaError::TooManyVariables`]: crate::errors::RaaError::TooManyVariables
     /// [`RaaError::VoidExpression`]: crate::errors::RaaError::VoidExpression
-    /// [`RaaError::UndefinedVariable`]: crate::errors::RaaError::UndefinedVariable
     pub fn generate_truth_table(&self, proposition: &Proposition) -> Result<TruthTable> {
         // Early validation prevents expensive operations on invalid inputs
         let variables = Self::extract_and_validate_variables(proposition)?;
 
-        // Initialize variable map only after validation succeeds
+        // Intern the proposition and initialize variable map only after validation succeeds
+        let mut arena = PropositionArena::new();
+        let root = arena.intern(proposition)?;
         *self.vars.borrow_mut() =
             variables
                 .iter()
                 .fold(BTreeMap::new(), |mut acc, variable_name| {
-                    acc.insert(variable_name.clone(), false);
+                    // All variables of the proposition are known to the arena after interning.
+                    if let Some(index) = arena.variable_index(variable_name) {
+                        acc.insert(variable_name.clone(), index);
+                    }
                     acc
                 });
 
@@ -264,18 +313,50 @@ impl TableGenerator {
         let line_count = (2usize).pow(variable_count as u32);
 
         // Pre-allocate with exact capacity to avoid reallocations
-        let lines = Vec::with_capacity(line_count);
-        let lines = (0..line_count).try_fold(lines, |mut lines, line_index| {
-            self.generate_table_line(line_index, proposition)
-                .map(|line| {
-                    lines.push(line);
-                    lines
-                })
-        })?;
+        let mut values = vec![false; arena.variables().len()];
+        let lines =
+            (0..line_count).fold(Vec::with_capacity(line_count), |mut lines, line_index| {
+                lines.push(self.generate_table_line(&arena, line_index, root, &mut values));
+                lines
+            });
 
         Ok(TruthTable { header, lines })
     }
 
+    /// Returns a lazy enumeration of the models of a proposition.
+    ///
+    /// The models are the rows of the truth table in which the proposition is TRUE, in the same
+    /// order. The rows are evaluated one at a time, so no truth table is stored. See [`Models`]
+    /// for projections and limits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{table_generator::TableGenerator, proposition::Proposition, disjunction::Disjunction};
+    ///
+    /// // P ∨ Q
+    /// let proposition = Proposition::Disjunction(Disjunction {
+    ///     left: Box::new("P".into()),
+    ///     right: Box::new("Q".into()),
+    /// });
+    ///
+    /// let generator = TableGenerator::new();
+    /// let first = generator.models(&proposition).next().unwrap().unwrap();
+    /// assert_eq!(first.to_string(), "P = F, Q = T");
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// The enumeration yields [`RaaError::TooManyVariables`] if the proposition and the projection
+    /// contain more than 16 variables and [`RaaError::VoidExpression`] if the proposition contains
+    /// invalid expressions.
+    ///
+    /// [`RaaError::TooManyVariables`]: crate::errors::RaaError::TooManyVariables
+    /// [`RaaError::VoidExpression`]: crate::errors::RaaError::VoidExpression
+    pub fn models(&self, proposition: &Proposition) -> Models<'_> {
+        Models::truth_table(proposition)
+    }
+
     /// Validates that the number of variables is within acceptable limits for truth table generation.
     ///
     /// This method performs early validation to prevent expensive operations on propositions
@@ -294,7 +375,7 @@ impl TableGenerator {
     ///
     /// This validation runs in O(1) time and should be called before any expensive
     /// variable extraction or map initialization operations.
-    fn validate_variable_count(variable_count: usize) -> Result<()> {
+    pub(crate) fn validate_variable_count(variable_count: usize) -> Result<()> {
         if variable_count > MAX_VARIABLES_IN_TRUTH_TABLE {
             let rows = (2u64).pow(variable_count as u32);
             // Estimate memory usage: each row has variable_count + 1 booleans (1 byte each)
@@ -374,29 +455,30 @@ impl TableGenerator {
 
     fn generate_table_line(
         &self,
+        arena: &PropositionArena,
         line_index: usize,
-        proposition: &Proposition,
-    ) -> Result<Vec<bool>> {
+        proposition: PropId,
+        values: &mut [bool],
+    ) -> Vec<bool> {
         let variable_count = self.number_of_variables();
         let mut bit_mask = if variable_count == 0 {
             0
         } else {
             1 << (variable_count - 1)
         };
-        let mut line = self.vars.borrow_mut().iter_mut().fold(
+        let mut line = self.vars.borrow().values().fold(
             Vec::with_capacity(variable_count + 1),
-            |mut truth_values, (_variable_name, variable_value)| {
+            |mut truth_values, variable_index| {
                 // Extract the variable value from the bits of line_index
                 // Each bit position corresponds to a variable's truth value
                 let current_bit_value = (line_index & bit_mask) != 0;
-                *variable_value = current_bit_value;
+                values[*variable_index] = current_bit_value;
                 truth_values.push(current_bit_value);
                 bit_mask >>= 1;
                 truth_values
             },
         );
-        let b = proposition.calculate_value(&self.vars.borrow())?;
-        line.push(b);
-        Ok(line)
+        line.push(arena.evaluate(proposition, values));
+        line
     }
 }
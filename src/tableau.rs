@@ -0,0 +1,472 @@
+use petgraph::{graph::NodeIndex, visit::EdgeRef};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Debug, Display, Error, Formatter, Write},
+};
+
+use crate::{
+    arena::PropositionArena,
+    assignment::Assignment,
+    negation::Negation,
+    proposition::Proposition,
+    prover::{PropositionTree, TransformationState},
+};
+
+/// The decomposition rules of the analytic tableaux method
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rule {
+    /// ¬¬A yields A
+    DoubleNegation,
+    /// A ∧ B yields A and B
+    Conjunction,
+    /// ¬(A ∧ B) branches into ¬A | ¬B
+    NegatedConjunction,
+    /// A ∨ B branches into A | B
+    Disjunction,
+    /// ¬(A ∨ B) yields ¬A and ¬B
+    NegatedDisjunction,
+    /// A → B branches into ¬A | B
+    Implication,
+    /// ¬(A → B) yields A and ¬B
+    NegatedImplication,
+    /// A ↔ B branches into A, B | ¬A, ¬B
+    BiImplication,
+    /// ¬(A ↔ B) branches into A, ¬B | ¬A, B
+    NegatedBiImplication,
+    /// A ⊕ B branches into A, ¬B | ¬A, B
+    ExclusiveDisjunction,
+    /// ¬(A ⊕ B) branches into A, B | ¬A, ¬B
+    NegatedExclusiveDisjunction,
+    /// A ↑ B branches into ¬A | ¬B
+    AlternativeDenial,
+    /// ¬(A ↑ B) yields A and B
+    NegatedAlternativeDenial,
+    /// A ↓ B yields ¬A and ¬B
+    JointDenial,
+    /// ¬(A ↓ B) branches into A | B
+    NegatedJointDenial,
+}
+
+impl Rule {
+    /// Returns the descriptive name of the rule.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rule::DoubleNegation => "Double negation",
+            Rule::Conjunction => "Conjunction",
+            Rule::NegatedConjunction => "Negated conjunction",
+            Rule::Disjunction => "Disjunction",
+            Rule::NegatedDisjunction => "Negated disjunction",
+            Rule::Implication => "Implication",
+            Rule::NegatedImplication => "Negated implication",
+            Rule::BiImplication => "Biimplication",
+            Rule::NegatedBiImplication => "Negated biimplication",
+            Rule::ExclusiveDisjunction => "Exclusive disjunction",
+            Rule::NegatedExclusiveDisjunction => "Negated exclusive disjunction",
+            Rule::AlternativeDenial => "Alternative denial",
+            Rule::NegatedAlternativeDenial => "Negated alternative denial",
+            Rule::JointDenial => "Joint denial",
+            Rule::NegatedJointDenial => "Negated joint denial",
+        }
+    }
+
+    /// Returns true if the rule splits a branch into two new branches.
+    pub fn is_branching(&self) -> bool {
+        matches!(
+            self,
+            Rule::NegatedConjunction
+                | Rule::Disjunction
+                | Rule::Implication
+                | Rule::BiImplication
+                | Rule::NegatedBiImplication
+                | Rule::ExclusiveDisjunction
+                | Rule::NegatedExclusiveDisjunction
+                | Rule::AlternativeDenial
+                | Rule::NegatedJointDenial
+        )
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match self {
+            Rule::DoubleNegation => write!(f, "¬¬"),
+            Rule::Conjunction => write!(f, "∧"),
+            Rule::NegatedConjunction => write!(f, "¬∧"),
+            Rule::Disjunction => write!(f, "∨"),
+            Rule::NegatedDisjunction => write!(f, "¬∨"),
+            Rule::Implication => write!(f, "→"),
+            Rule::NegatedImplication => write!(f, "¬→"),
+            Rule::BiImplication => write!(f, "↔"),
+            Rule::NegatedBiImplication => write!(f, "¬↔"),
+            Rule::ExclusiveDisjunction => write!(f, "⊕"),
+            Rule::NegatedExclusiveDisjunction => write!(f, "¬⊕"),
+            Rule::AlternativeDenial => write!(f, "↑"),
+            Rule::NegatedAlternativeDenial => write!(f, "¬↑"),
+            Rule::JointDenial => write!(f, "↓"),
+            Rule::NegatedJointDenial => write!(f, "¬↓"),
+        }
+    }
+}
+
+/// A single row of a truth tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableauNode {
+    /// The index of this node in [`Tableau::nodes`]
+    pub id: usize,
+
+    /// The proposition of this row
+    pub proposition: Proposition,
+
+    /// Whether the node has been decomposed or closes its branch
+    pub state: TransformationState,
+
+    /// The node directly above this node in the tree, `None` for the root
+    pub parent: Option<usize>,
+
+    /// The nodes directly below this node, ordered from left to right
+    pub children: Vec<usize>,
+
+    /// The node whose decomposition produced this row.
+    /// It is `None` for the root propositions, e.g. the premises of an argument.
+    pub derived_from: Option<usize>,
+
+    /// The rule that was applied to decompose this node.
+    /// It is `None` for literals and for nodes that were not decomposed.
+    pub rule: Option<Rule>,
+}
+
+/// A path from the root of a truth tree to one of its leaves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Branch {
+    /// The node indices along the branch, starting with the root
+    pub nodes: Vec<usize>,
+
+    /// The pair of contradicting nodes if the branch is closed.
+    /// Both indices are equal if a node with the constant FALSE closes the branch on its own.
+    pub closed_by: Option<(usize, usize)>,
+}
+
+impl Branch {
+    /// Returns true if the branch contains a contradiction.
+    pub fn is_closed(&self) -> bool {
+        self.closed_by.is_some()
+    }
+
+    /// Returns the index of the leaf node of the branch.
+    pub fn leaf(&self) -> usize {
+        // A branch contains at least the root node.
+        self.nodes[self.nodes.len() - 1]
+    }
+}
+
+/// A truth tree built by the [`Prover`]
+///
+/// The tableau is a snapshot of the internal proof structure. The node with index 0 is the root.
+/// If the tree was started with several propositions, like the premises of an argument, they
+/// form a single branch below the root.
+/// Nodes are numbered in the order in which they were added to the tree.
+///
+/// [`Prover`]: crate::prover::Prover
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tableau {
+    /// All nodes of the tree
+    pub nodes: Vec<TableauNode>,
+
+    /// All branches of the tree, ordered from left to right
+    pub branches: Vec<Branch>,
+
+    /// True if the root holds the negation of the proposition to prove.
+    /// Such a tableau is a refutation attempt.
+    pub negated: bool,
+}
+
+impl Tableau {
+    /// Returns the root node of the tree.
+    pub fn root(&self) -> &TableauNode {
+        &self.nodes[0]
+    }
+
+    /// Returns true if all branches of the tree are closed.
+    pub fn is_closed(&self) -> bool {
+        self.branches.iter().all(Branch::is_closed)
+    }
+
+    /// Returns an iterator over the branches that are not closed.
+    pub fn open_branches(&self) -> impl Iterator<Item = &Branch> {
+        self.branches.iter().filter(|b| !b.is_closed())
+    }
+
+    /// Reads the variable assignment off a branch.
+    ///
+    /// The literals on the branch determine the values of their variables. All other variables of
+    /// the tree are set to false. For an open and completely developed branch the
+    /// result satisfies all propositions on the branch.
+    pub fn assignment(&self, branch: &Branch) -> Assignment {
+        let mut assignment = self
+            .nodes
+            .iter()
+            .flat_map(|n| n.proposition.get_variables())
+            .map(|v| (v, false))
+            .collect::<Assignment>();
+        for id in &branch.nodes {
+            match &self.nodes[*id].proposition {
+                Proposition::Atom(a) => assignment.set(a.clone(), true),
+                Proposition::Negation(Negation { inner }) => {
+                    if let Proposition::Atom(a) = &**inner {
+                        assignment.set(a.clone(), false)
+                    }
+                }
+                _ => (),
+            }
+        }
+        assignment
+    }
+
+    /// Renders the tree in the Graphviz DOT language.
+    ///
+    /// Each node is labeled with its line number, its proposition and its state marker (✓ for
+    /// decomposed nodes, ✖ for leaves of closed branches). Derived nodes additionally show the rule
+    /// and the line they were derived from. The nodes of the contradicting pairs are highlighted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{prover::Prover, proposition::Proposition};
+    ///
+    /// let proof = Prover::new().prove_with_tableaux(&"p".into()).unwrap();
+    /// let dot = proof.tableau().to_dot();
+    /// assert!(dot.starts_with("digraph tableau {"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let closing_nodes = self.closing_nodes();
+        let mut dot = String::new();
+        // Writing to a String can't fail.
+        let _ = writeln!(dot, "digraph tableau {{");
+        let _ = writeln!(dot, "    node [shape=box, fontname=\"monospace\"];");
+        for node in &self.nodes {
+            let mut label = escape_dot(&self.node_label(node));
+            if let Some(justification) = self.justification(node) {
+                label.push_str("\\n");
+                label.push_str(&escape_dot(&justification));
+            }
+            let highlight = if closing_nodes.contains(&node.id) {
+                ", color=red, fontcolor=red"
+            } else {
+                ""
+            };
+            let _ = writeln!(dot, "    n{} [label=\"{}\"{}];", node.id, label, highlight);
+        }
+        for node in &self.nodes {
+            for child in &node.children {
+                let _ = writeln!(dot, "    n{} -> n{};", node.id, child);
+            }
+        }
+        let _ = writeln!(dot, "}}");
+        dot
+    }
+
+    /// Renders the tree as a Mermaid flowchart.
+    ///
+    /// The labels are the same as for [`Tableau::to_dot`]. The nodes of the contradicting pairs
+    /// are assigned to the class `closing`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raa_tt::{prover::Prover, proposition::Proposition};
+    ///
+    /// let proof = Prover::new().prove_with_tableaux(&"p".into()).unwrap();
+    /// let mermaid = proof.tableau().to_mermaid();
+    /// assert!(mermaid.starts_with("flowchart TD"));
+    /// ```
+    pub fn to_mermaid(&self) -> String {
+        let closing_nodes = self.closing_nodes();
+        let mut mermaid = String::new();
+        let _ = writeln!(mermaid, "flowchart TD");
+        for node in &self.nodes {
+            let mut label = escape_mermaid(&self.node_label(node));
+            if let Some(justification) = self.justification(node) {
+                label.push_str("<br/>");
+                label.push_str(&escape_mermaid(&justification));
+            }
+            let _ = writeln!(mermaid, "    n{}[\"{}\"]", node.id, label);
+        }
+        for node in &self.nodes {
+            for child in &node.children {
+                let _ = writeln!(mermaid, "    n{} --> n{}", node.id, child);
+            }
+        }
+        if !closing_nodes.is_empty() {
+            let _ = writeln!(mermaid, "    classDef closing stroke:#d00,color:#d00");
+            let _ = writeln!(
+                mermaid,
+                "    class {} closing",
+                closing_nodes
+                    .iter()
+                    .map(|id| format!("n{id}"))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            );
+        }
+        mermaid
+    }
+
+    // The main label of a node: line number, proposition and state marker.
+    // Line numbers start with 1 as it is common in logic textbooks.
+    fn node_label(&self, node: &TableauNode) -> String {
+        match node.state {
+            TransformationState::Unprocessed => format!("{}. {}", node.id + 1, node.proposition),
+            TransformationState::Transformed if node.rule.is_none() => {
+                format!("{}. {}", node.id + 1, node.proposition)
+            }
+            _ => format!("{}. {} {}", node.id + 1, node.proposition, node.state),
+        }
+    }
+
+    // The justification of a derived node, e.g. "from 3, ∧".
+    fn justification(&self, node: &TableauNode) -> Option<String> {
+        let origin = &self.nodes[node.derived_from?];
+        Some(format!("from {}, {}", origin.id + 1, origin.rule?))
+    }
+
+    fn closing_nodes(&self) -> BTreeSet<usize> {
+        self.branches
+            .iter()
+            .filter_map(|b| b.closed_by)
+            .flat_map(|(a, b)| [a, b])
+            .collect()
+    }
+
+    pub(crate) fn from_tree(
+        graph: &PropositionTree,
+        arena: &PropositionArena,
+        negated: bool,
+    ) -> Self {
+        let mut nodes = graph
+            .node_indices()
+            .map(|i| {
+                let node = &graph[i];
+                TableauNode {
+                    id: i.index(),
+                    proposition: arena.to_proposition(node.proposition),
+                    state: node.state,
+                    parent: None,
+                    children: Vec::new(),
+                    derived_from: node.derived_from.map(|d| d.index()),
+                    rule: node.rule,
+                }
+            })
+            .collect::<Vec<_>>();
+        for edge in graph.edge_references() {
+            nodes[edge.source().index()]
+                .children
+                .push(edge.target().index());
+            nodes[edge.target().index()].parent = Some(edge.source().index());
+        }
+        nodes.iter_mut().for_each(|n| n.children.sort_unstable());
+
+        // Collect the branches from left to right by a depth first traversal.
+        let mut branches = Vec::new();
+        let mut stack = vec![(0, vec![0])];
+        while let Some((id, path)) = stack.pop() {
+            if nodes[id].children.is_empty() {
+                let closed_by = graph[NodeIndex::new(id)]
+                    .closed_by
+                    .map(|(a, b)| (a.index(), b.index()));
+                branches.push(Branch {
+                    nodes: path,
+                    closed_by,
+                });
+            } else {
+                for child in nodes[id].children.iter().rev() {
+                    let mut path = path.clone();
+                    path.push(*child);
+                    stack.push((*child, path));
+                }
+            }
+        }
+
+        Self {
+            nodes,
+            branches,
+            negated,
+        }
+    }
+}
+
+/// Renders the tree as indented text in the style used in logic textbooks.
+///
+/// Each line shows the line number, the proposition, a ✓ if the node has been decomposed and the
+/// justification column with the line and the rule it was derived from. Branches are drawn with
+/// box characters and each closed branch ends with ✖ and the numbers of the contradicting lines,
+/// or the number of the line with the constant FALSE.
+impl Display for Tableau {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        // Rows are pairs of tree column and justification column.
+        let mut rows: Vec<(String, String)> = Vec::with_capacity(self.nodes.len());
+        let closed_by = self
+            .branches
+            .iter()
+            .filter_map(|b| b.closed_by.map(|c| (b.leaf(), c)))
+            .collect::<BTreeMap<_, _>>();
+        let mut stack = vec![(0, String::new(), String::new())];
+        while let Some((id, first_prefix, rest_prefix)) = stack.pop() {
+            let node = &self.nodes[id];
+            let marker = if node.rule.is_some() { " ✓" } else { "" };
+            rows.push((
+                format!("{first_prefix}{}. {}{marker}", id + 1, node.proposition),
+                self.justification(node).unwrap_or_default(),
+            ));
+            match closed_by.get(&id) {
+                Some((a, b)) if a == b => {
+                    rows.push((format!("{rest_prefix}✖ ({})", a + 1), String::new()))
+                }
+                Some((a, b)) => rows.push((
+                    format!("{rest_prefix}✖ ({}, {})", a + 1, b + 1),
+                    String::new(),
+                )),
+                None => (),
+            }
+            match node.children.as_slice() {
+                [] => (),
+                [child] => stack.push((*child, rest_prefix.clone(), rest_prefix)),
+                children => {
+                    for (i, child) in children.iter().enumerate().rev() {
+                        let last = i == children.len() - 1;
+                        stack.push((
+                            *child,
+                            format!("{rest_prefix}{}", if last { "└── " } else { "├── " }),
+                            format!("{rest_prefix}{}", if last { "    " } else { "│   " }),
+                        ));
+                    }
+                }
+            }
+        }
+        let width = rows
+            .iter()
+            .map(|(tree, _)| tree.chars().count())
+            .max()
+            .unwrap_or_default();
+        rows.iter().try_for_each(|(tree, justification)| {
+            if justification.is_empty() {
+                writeln!(f, "{tree}")
+            } else {
+                let padding = width - tree.chars().count();
+                writeln!(f, "{tree}{}   {justification}", " ".repeat(padding))
+            }
+        })
+    }
+}
+
+fn escape_dot(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// Mermaid interprets some characters in labels, so we use its entity codes instead.
+fn escape_mermaid(label: &str) -> String {
+    label
+        .replace('&', "#amp;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+        .replace('"', "#quot;")
+}
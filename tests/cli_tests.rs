@@ -0,0 +1,53 @@
+use std::{collections::BTreeSet, process::Command};
+
+use anyhow::Result;
+
+// Runs the command line tool with the given arguments and returns its standard output.
+fn raa_tt(args: &[&str]) -> Result<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_raa_tt"))
+        .args(args)
+        .output()?;
+    assert!(output.status.success(), "{args:?}: {output:?}");
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[test]
+fn enumerate_models_with_each_engine() -> Result<()> {
+    for engine in ["tableau", "truth-table", "dpll", "cdcl"] {
+        let output = raa_tt(&["-q", "-s", "a | b | c | d", "--models", "--engine", engine])?;
+        let models = output
+            .lines()
+            .filter(|line| line.starts_with("a = "))
+            .collect::<BTreeSet<_>>();
+        assert_eq!(15, models.len(), "{engine}: {output}");
+        assert!(!models.contains("a = F, b = F, c = F, d = F"), "{engine}");
+    }
+    Ok(())
+}
+
+#[test]
+fn emit_dimacs_of_a_single_statement() -> Result<()> {
+    let output = raa_tt(&["-s", "p -> q, p |- q", "--emit", "dimacs"])?;
+    assert_eq!(1, output.lines().filter(|l| l.starts_with("p cnf")).count());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_raa_tt"))
+        .args(["-s", "p | q\np & q", "--emit", "dimacs"])
+        .output()?;
+    assert!(!output.status.success());
+    assert!(!String::from_utf8(output.stdout)?.contains("p cnf"));
+    Ok(())
+}
+
+#[test]
+fn print_with_minimal_parentheses() -> Result<()> {
+    let output = raa_tt(&["-q", "-s", "(p & q) -> p", "--pretty", "-t"])?;
+    assert!(output.contains("p & q -> p is Logically True"), "{output}");
+    assert!(output.contains("p | q | p & q -> p | "), "{output}");
+
+    let output = raa_tt(&["-q", "-s", "p -> (q -> r), p |- q -> r", "--pretty"])?;
+    assert!(output.contains("p -> (q -> r), p |- q -> r is Valid"), "{output}");
+
+    let output = raa_tt(&["-q", "-s", "(p & q) -> p"])?;
+    assert!(output.contains("((p & q) -> p) is Logically True"), "{output}");
+    Ok(())
+}
@@ -1,13 +1,28 @@
+use std::time::Duration;
+
 use anyhow::Result;
 use raa_tt::{
+    argument::{Argument, Statement, Validity},
+    assignment::Assignment,
+    cdcl::CdclSolver,
+    cnf::{ClauseSet, Literal},
+    config::{CancellationToken, Limit, ProverConfig},
     conjunction::Conjunction,
+    decider::Decider,
     disjunction::Disjunction,
+    dpll::DpllSolver,
     errors::RaaError,
+    models::Models,
+    negation::Negation,
+    notation::Notation,
+    pretty::PrettyPrinter,
     proposition::Proposition,
-    prover::{ProveResult, Prover},
-    raa_tt_grammar::RaaTtGrammar,
+    prover::{Equivalence, ProveResult, Prover},
+    raa_tt_grammar::{Associativity, RaaTtGrammar},
     raa_tt_parser::parse,
+    strategy::ExpansionStrategy,
     table_generator::TableGenerator,
+    tableau::Rule,
 };
 
 pub const PROPOSITIONS: &[(&str, ProveResult)] = &[
@@ -62,6 +77,20 @@ pub const PROPOSITIONS: &[(&str, ProveResult)] = &[
     ("q -> (p -> q)", ProveResult::Proven),
     ("p & !p -> q", ProveResult::Proven),
     ("p -> (q | !q)", ProveResult::Proven),
+    ("p -> F", ProveResult::Contingent),
+    ("(p -> false) <-> !p", ProveResult::Proven),
+    ("p & T -> p", ProveResult::Proven),
+    ("F | q & !q", ProveResult::Falsified),
+    ("true -> (⊥ -> p)", ProveResult::Proven),
+    ("!(⊤ | p) -> q", ProveResult::Proven),
+    ("p ^ q <-> !(p <-> q)", ProveResult::Proven),
+    ("p NAND q <-> !(p & q)", ProveResult::Proven),
+    ("p NOR q <-> !p & !q", ProveResult::Proven),
+    ("p ^ q ^ r", ProveResult::Contingent),
+    ("!(p NAND q) -> p", ProveResult::Proven),
+    ("!(p NOR q) -> p | q", ProveResult::Proven),
+    ("!(p ^ q) & p -> q", ProveResult::Proven),
+    ("p ^ p", ProveResult::Falsified),
 ];
 
 #[test]
@@ -80,6 +109,1428 @@ fn compile_and_prove() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn prove_with_witness() -> Result<()> {
+    for (i, (p, r)) in PROPOSITIONS.iter().enumerate() {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        let file_name = format!("example_{i}");
+        parse(p, &file_name, &mut raa_tt_grammar)?;
+        let proposition: Proposition =
+            (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+        let witness = Prover::new().prove_with_witness(&proposition)?;
+        assert_eq!(*r, witness.result);
+        match witness.result {
+            ProveResult::Proven => assert!(witness.counterexample.is_none()),
+            ProveResult::Falsified => assert!(witness.model.is_none()),
+            _ => (),
+        }
+        if let Some(counterexample) = &witness.counterexample {
+            assert!(
+                !proposition.evaluate(counterexample)?,
+                "{p}: {counterexample}"
+            );
+        }
+        if let Some(model) = &witness.model {
+            assert!(proposition.evaluate(model)?, "{p}: {model}");
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn prove_with_tableaux() -> Result<()> {
+    for (i, (p, r)) in PROPOSITIONS.iter().enumerate() {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        let file_name = format!("example_{i}");
+        parse(p, &file_name, &mut raa_tt_grammar)?;
+        let proposition: Proposition =
+            (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+        let proof = Prover::new().prove_with_tableaux(&proposition)?;
+        assert_eq!(*r, proof.result);
+        assert!(proof.tableaux[0].negated);
+        match proof.result {
+            ProveResult::Proven => {
+                assert_eq!(1, proof.tableaux.len());
+                assert!(proof.tableaux[0].is_closed());
+            }
+            ProveResult::Falsified => {
+                assert_eq!(2, proof.tableaux.len());
+                assert!(proof.tableaux[1].is_closed());
+            }
+            _ => {
+                // The second tableau is only built if no model could be guessed.
+                assert!(!proof.tableaux.is_empty() && proof.tableaux.len() <= 2);
+                assert!(proof.tableaux.iter().all(|t| !t.is_closed()));
+            }
+        }
+        for tableau in &proof.tableaux {
+            assert!(tableau.root().parent.is_none());
+            for node in tableau.nodes.iter().skip(1) {
+                let parent = node.parent.expect("Each node but the root has a parent");
+                assert!(tableau.nodes[parent].children.contains(&node.id));
+                let origin = node
+                    .derived_from
+                    .expect("Each node but the root is derived");
+                assert!(tableau.nodes[origin].rule.is_some());
+            }
+            for branch in &tableau.branches {
+                if let Some((a, b)) = branch.closed_by {
+                    assert!(branch.nodes.contains(&a) && branch.nodes.contains(&b));
+                    if a == b {
+                        // F closes the branch on its own, so does !T
+                        assert!(matches!(
+                            tableau.nodes[a].proposition.to_string().as_str(),
+                            "F" | "!T"
+                        ));
+                        continue;
+                    }
+                    let (a, b) = (&tableau.nodes[a].proposition, &tableau.nodes[b].proposition);
+                    assert!(
+                        *a == Proposition::Negation(Negation {
+                            inner: Box::new(b.clone())
+                        }) || *b
+                            == Proposition::Negation(Negation {
+                                inner: Box::new(a.clone())
+                            })
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn export_tableau() -> Result<()> {
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse("p & (p -> q) -> q", "export", &mut raa_tt_grammar)?;
+    let proposition: Proposition =
+        (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+    let proof = Prover::new().prove_with_tableaux(&proposition)?;
+    let tableau = proof.tableau();
+
+    let dot = tableau.to_dot();
+    assert!(dot.starts_with("digraph tableau {"));
+    assert!(dot.contains(r#"n0 [label="1. !((p & (p -> q)) -> q) ✓"];"#));
+    assert!(dot.contains(r#"n5 [label="6. !p ✖\nfrom 5, →", color=red, fontcolor=red];"#));
+    assert_eq!(tableau.nodes.len() - 1, dot.matches(" -> n").count());
+
+    let mermaid = tableau.to_mermaid();
+    assert!(mermaid.starts_with("flowchart TD"));
+    assert!(mermaid.contains(r#"n1["2. (p #amp; (p -#gt; q)) ✓<br/>from 1, ¬→"]"#));
+    assert!(mermaid.contains("class n2,n3,n5,n6 closing"));
+    Ok(())
+}
+
+#[test]
+fn explain_tableau() -> Result<()> {
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse("p & (p -> q) -> q", "explain", &mut raa_tt_grammar)?;
+    let proposition: Proposition =
+        (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+    let proof = Prover::new().prove_with_tableaux(&proposition)?;
+    let expected = "\
+1. !((p & (p -> q)) -> q) ✓
+2. (p & (p -> q)) ✓           from 1, ¬→
+3. !q                         from 1, ¬→
+4. p                          from 2, ∧
+5. (p -> q) ✓                 from 2, ∧
+├── 6. !p                     from 5, →
+│   ✖ (4, 6)
+└── 7. q                      from 5, →
+    ✖ (3, 7)
+";
+    assert_eq!(expected, proof.tableau().to_string());
+    Ok(())
+}
+
+#[test]
+fn proof_statistics() -> Result<()> {
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse("p & (p -> q) -> q", "statistics", &mut raa_tt_grammar)?;
+    let proposition: Proposition =
+        (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+    let proof = Prover::new().prove_with_tableaux(&proposition)?;
+    let stats = &proof.stats;
+    assert_eq!(proof.tableau().nodes.len(), stats.nodes);
+    assert_eq!(2, stats.branches_opened);
+    assert_eq!(2, stats.branches_closed);
+    assert_eq!(6, stats.max_depth);
+    assert_eq!(
+        vec![
+            (Rule::Conjunction, 1),
+            (Rule::Implication, 1),
+            (Rule::NegatedImplication, 1)
+        ],
+        stats
+            .rule_applications
+            .iter()
+            .map(|(r, c)| (*r, *c))
+            .collect::<Vec<_>>()
+    );
+
+    // The statistics of both truth trees of a contingent proposition are summed up
+    let witness = Prover::new().prove_with_witness(&"p".into())?;
+    assert_eq!(ProveResult::Contingent, witness.result);
+    assert!(witness.stats.nodes >= 1);
+
+    let premises = [proposition.clone(), "p".into()];
+    let (validity, tableau, stats) = Prover::new().entails_with_tableau(&premises, &"q".into())?;
+    assert!(matches!(validity, Validity::Invalid(_)));
+    assert_eq!(tableau.nodes.len(), stats.nodes);
+    Ok(())
+}
+
+pub const ARGUMENTS: &[(&str, bool)] = &[
+    ("p -> q, p |- q", true),
+    ("p -> q, !q |- !p", true),
+    ("p | q, !p |- q", true),
+    ("p -> q, q -> r |- p -> r", true),
+    ("p, !p |- q", true),
+    ("p -> q, q |- p", false),
+    ("p -> q, !p |- !q", false),
+    ("p | q |- p", false),
+];
+
+#[test]
+fn check_arguments() -> Result<()> {
+    for (i, (a, valid)) in ARGUMENTS.iter().enumerate() {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        let file_name = format!("argument_{i}");
+        parse(a, &file_name, &mut raa_tt_grammar)?;
+        let raa_tt_list = &raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list;
+        assert_eq!(1, raa_tt_list.len());
+        let Statement::Argument(argument) = Statement::from(&raa_tt_list[0]) else {
+            panic!("{a} should be parsed as argument");
+        };
+        let prover = Prover::new();
+        match prover.entails(&argument.premises, &argument.conclusion)? {
+            Validity::Valid => assert!(valid, "{a} should be invalid"),
+            Validity::Invalid(counterexample) => {
+                assert!(!valid, "{a} should be valid");
+                for premise in &argument.premises {
+                    assert!(premise.evaluate(&counterexample)?);
+                }
+                assert!(!argument.conclusion.evaluate(&counterexample)?);
+            }
+        }
+        // The argument is valid iff the corresponding implication is a tautology.
+        let proposition: Proposition = (&raa_tt_list[0]).into();
+        assert_eq!(
+            *valid,
+            prover.prove(&proposition)? == ProveResult::Proven,
+            "{proposition}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn arguments_without_premises() -> Result<()> {
+    let statements = |input: &str| -> Result<Vec<Statement>> {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        parse(input, "theorems", &mut raa_tt_grammar)?;
+        Ok(raa_tt_grammar.statements())
+    };
+    let [Statement::Argument(argument)] = &statements("|- p | !p")?[..] else {
+        panic!("|- p | !p should be parsed as a single argument");
+    };
+    assert!(argument.premises.is_empty());
+    assert_eq!("|- (p | !p)", argument.to_string());
+    assert_eq!(
+        Validity::Valid,
+        Prover::new().entails(&argument.premises, &argument.conclusion)?
+    );
+
+    // They can start the input and follow arguments
+    let statements = statements("|- p\np, p -> q |- q\n|- q ⊢ r\np")?;
+    assert_eq!(
+        vec!["|- p", "p, (p -> q) |- q", "|- q", "|- r", "p"],
+        statements
+            .iter()
+            .map(|statement| match statement {
+                Statement::Proposition(proposition) => proposition.to_string(),
+                Statement::Argument(argument) => argument.to_string(),
+            })
+            .collect::<Vec<_>>()
+    );
+
+    // After a proposition the turnstile continues an argument with premises
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse("p\n|- q", "theorems", &mut raa_tt_grammar)?;
+    assert_eq!(
+        vec![Statement::Argument(Argument {
+            premises: vec!["p".into()],
+            conclusion: "q".into(),
+        })],
+        raa_tt_grammar.statements()
+    );
+    Ok(())
+}
+
+#[test]
+fn check_satisfiability() -> Result<()> {
+    let prover = Prover::new();
+    for (i, (p, r)) in PROPOSITIONS.iter().enumerate() {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        let file_name = format!("example_{i}");
+        parse(p, &file_name, &mut raa_tt_grammar)?;
+        let proposition: Proposition =
+            (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+        match prover.is_satisfiable(&proposition)? {
+            Some(model) => {
+                assert_ne!(ProveResult::Falsified, *r, "{p}");
+                assert!(proposition.evaluate(&model)?, "{p}: {model}");
+            }
+            None => assert_eq!(ProveResult::Falsified, *r, "{p}"),
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn check_consistency() -> Result<()> {
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse("p -> q  q -> r  p  !r", "consistency", &mut raa_tt_grammar)?;
+    let propositions = raa_tt_grammar
+        .raa_tt
+        .as_ref()
+        .unwrap()
+        .raa_tt_list
+        .iter()
+        .map(Proposition::from)
+        .collect::<Vec<_>>();
+    let prover = Prover::new();
+    assert!(prover.is_consistent(&propositions)?.is_none());
+    for i in 0..propositions.len() {
+        let mut subset = propositions.clone();
+        subset.remove(i);
+        let model = prover
+            .is_consistent(&subset)?
+            .expect("Each proper subset is consistent");
+        for proposition in &subset {
+            assert!(proposition.evaluate(&model)?, "{proposition}: {model}");
+        }
+    }
+    assert!(prover.is_consistent(&[])?.is_some());
+    Ok(())
+}
+
+#[test]
+fn expansion_strategies() -> Result<()> {
+    let strategies = [
+        ExpansionStrategy::InOrder,
+        ExpansionStrategy::AlphaFirst,
+        ExpansionStrategy::SmallestFirst,
+        ExpansionStrategy::MostConstrainedFirst,
+        // Largest formulas first
+        ExpansionStrategy::custom(|candidate| -(candidate.size() as i64)),
+    ];
+    for strategy in strategies {
+        let prover = Prover::with_strategy(strategy.clone());
+        for (i, (p, r)) in PROPOSITIONS.iter().enumerate() {
+            let mut raa_tt_grammar = RaaTtGrammar::new();
+            let file_name = format!("example_{i}");
+            parse(p, &file_name, &mut raa_tt_grammar)?;
+            let proposition: Proposition =
+                (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+            let witness = prover.prove_with_witness(&proposition)?;
+            assert_eq!(*r, witness.result, "{p} with {strategy:?}");
+            if let Some(counterexample) = &witness.counterexample {
+                assert!(!proposition.evaluate(counterexample)?);
+            }
+        }
+    }
+
+    // The conjunction should be decomposed before the disjunction splits the branch
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse(
+        "(p | q) & (!p & !q & r)",
+        "alpha_first",
+        &mut raa_tt_grammar,
+    )?;
+    let proposition: Proposition =
+        (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+    let node_count = |strategy| -> Result<usize> {
+        let proof = Prover::with_strategy(strategy).prove_with_tableaux(&proposition)?;
+        assert_eq!(ProveResult::Falsified, proof.result);
+        Ok(proof.tableaux.iter().map(|t| t.nodes.len()).sum())
+    };
+    assert!(node_count(ExpansionStrategy::AlphaFirst)? < node_count(ExpansionStrategy::InOrder)?);
+    Ok(())
+}
+
+#[test]
+fn prove_long_formula() -> Result<()> {
+    // Hypothetical syllogism over a chain of 30 implications
+    let chain = (0..30)
+        .map(|i| format!("(p{i} -> p{})", i + 1))
+        .collect::<Vec<_>>()
+        .join(" & ");
+    let input = format!("{chain} -> (p0 -> p30)");
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse(&input, "long_formula", &mut raa_tt_grammar)?;
+    let proposition: Proposition =
+        (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+    let prover = Prover::new();
+    assert_eq!(ProveResult::Proven, prover.prove(&proposition)?);
+    let witness = prover.prove_with_witness(&Proposition::Negation(Negation {
+        inner: Box::new(proposition),
+    }))?;
+    assert_eq!(ProveResult::Falsified, witness.result);
+    Ok(())
+}
+
+#[test]
+fn prove_in_parallel() -> Result<()> {
+    // The prover keeps no state between proofs, so it can be shared between threads
+    let prover = Prover::new();
+    let results = std::thread::scope(|scope| {
+        let handles = PROPOSITIONS
+            .iter()
+            .enumerate()
+            .map(|(i, (p, r))| {
+                let prover = &prover;
+                scope.spawn(move || -> Result<()> {
+                    let mut raa_tt_grammar = RaaTtGrammar::new();
+                    let file_name = format!("example_{i}");
+                    parse(p, &file_name, &mut raa_tt_grammar)?;
+                    let proposition: Proposition =
+                        (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional)
+                            .into();
+                    assert_eq!(*r, prover.prove(&proposition)?, "{p}");
+                    Ok(())
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("The proof doesn't panic"))
+            .collect::<Vec<_>>()
+    });
+    results.into_iter().collect()
+}
+
+#[test]
+fn resource_limits() -> Result<()> {
+    let chain = (0..30)
+        .map(|i| format!("(p{i} -> p{})", i + 1))
+        .collect::<Vec<_>>()
+        .join(" & ");
+    let input = format!("{chain} -> (p0 -> p30)");
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse(&input, "resource_limits", &mut raa_tt_grammar)?;
+    let proposition: Proposition =
+        (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+
+    let gave_up = |config: ProverConfig| match Prover::with_config(config).prove(&proposition) {
+        Err(RaaError::GaveUp { limit, stats }) => Some((limit, stats)),
+        _ => None,
+    };
+    let (limit, stats) = gave_up(ProverConfig {
+        max_nodes: Some(50),
+        ..Default::default()
+    })
+    .expect("Node limit should be exceeded");
+    assert_eq!(Limit::Nodes(50), limit);
+    assert!(stats.nodes > 50);
+    let (limit, stats) = gave_up(ProverConfig {
+        max_steps: Some(10),
+        ..Default::default()
+    })
+    .expect("Step limit should be exceeded");
+    assert_eq!(Limit::Steps(10), limit);
+    assert_eq!(11, stats.steps);
+    let (limit, _) = gave_up(ProverConfig {
+        timeout: Some(Duration::ZERO),
+        ..Default::default()
+    })
+    .expect("Deadline should be exceeded");
+    assert_eq!(Limit::Deadline, limit);
+    let token = CancellationToken::new();
+    token.cancel();
+    let (limit, _) = gave_up(ProverConfig {
+        cancellation: Some(token),
+        ..Default::default()
+    })
+    .expect("Proof should be cancelled");
+    assert_eq!(Limit::Cancelled, limit);
+
+    // Sufficient limits don't change the result
+    let prover = Prover::with_config(
+        ProverConfig {
+            max_nodes: Some(100_000),
+            max_steps: Some(100_000),
+            cancellation: Some(CancellationToken::new()),
+            ..Default::default()
+        }
+        .with_timeout(Duration::from_secs(60)),
+    );
+    assert_eq!(ProveResult::Proven, prover.prove(&proposition)?);
+
+    // The timeout starts anew with each call
+    let prover =
+        Prover::with_config(ProverConfig::default().with_timeout(Duration::from_millis(100)));
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(ProveResult::Contingent, prover.prove(&"p".into())?);
+    Ok(())
+}
+
+#[test]
+fn decision_engines() -> Result<()> {
+    let deciders: [Box<dyn Decider>; 4] = [
+        Box::new(Prover::new()),
+        Box::new(TableGenerator::new()),
+        Box::new(DpllSolver::new()),
+        Box::new(CdclSolver::new()),
+    ];
+    for decider in &deciders {
+        for (i, (p, r)) in PROPOSITIONS.iter().enumerate() {
+            let mut raa_tt_grammar = RaaTtGrammar::new();
+            let file_name = format!("example_{i}");
+            parse(p, &file_name, &mut raa_tt_grammar)?;
+            let proposition: Proposition =
+                (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+            assert_eq!(*r, decider.classify(&proposition)?, "{p}");
+            let model = decider.find_model(&proposition)?;
+            assert_eq!(*r != ProveResult::Falsified, model.is_some(), "{p}");
+            if let Some(model) = model {
+                assert!(proposition.evaluate(&model)?, "{p}");
+            }
+        }
+    }
+
+    // Too many variables for a truth table
+    let proposition = create_proposition_with_variables(40);
+    assert!(matches!(
+        TableGenerator::new().classify(&proposition),
+        Err(RaaError::TooManyVariables { .. })
+    ));
+    let solver = DpllSolver::new();
+    assert_eq!(ProveResult::Contingent, solver.classify(&proposition)?);
+    let model = solver.find_model(&proposition)?.unwrap();
+    assert_eq!(40, model.len());
+    assert!(proposition.evaluate(&model)?);
+    Ok(())
+}
+
+#[test]
+fn conjunctive_normal_forms() -> Result<()> {
+    // All assignments of the given number of variables
+    let assignments = |count: usize| {
+        (0..1u32 << count)
+            .map(move |bits| (0..count).map(|i| bits & (1 << i) != 0).collect::<Vec<_>>())
+    };
+    for (i, (p, _)) in PROPOSITIONS.iter().enumerate() {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        let file_name = format!("example_{i}");
+        parse(p, &file_name, &mut raa_tt_grammar)?;
+        let proposition: Proposition =
+            (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+
+        // The distributed form is equivalent to the proposition
+        let cnf = proposition.to_cnf()?;
+        assert_eq!(cnf.variables.len(), cnf.variable_count, "{p}");
+        for values in assignments(cnf.variable_count) {
+            let assignment = cnf.variables.iter().cloned().zip(values.clone()).collect();
+            assert_eq!(
+                proposition.evaluate(&assignment)?,
+                cnf.evaluate(&values),
+                "{p}"
+            );
+        }
+
+        // The Tseitin encoding has exactly one model for each model of the proposition
+        let tseitin = proposition.to_tseitin_cnf()?;
+        assert_eq!(tseitin.variables, cnf.variables, "{p}");
+        let mut models = 0;
+        for values in assignments(tseitin.variable_count) {
+            if tseitin.evaluate(&values) {
+                models += 1;
+                let assignment = tseitin.variables.iter().cloned().zip(values).collect();
+                assert!(proposition.evaluate(&assignment)?, "{p}");
+            }
+        }
+        let expected = assignments(cnf.variable_count)
+            .filter(|values| cnf.evaluate(values))
+            .count();
+        assert_eq!(expected, models, "{p}");
+    }
+
+    // Tautologies have no clauses
+    let tautology = Proposition::Disjunction(Disjunction {
+        left: Box::new("p".into()),
+        right: Box::new(Proposition::Negation(Negation {
+            inner: Box::new("p".into()),
+        })),
+    });
+    assert!(tautology.to_cnf()?.clauses.is_empty());
+    assert_eq!("⊤", tautology.to_cnf()?.to_string());
+
+    // Clauses that differ in the order of their literals only are kept once
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse(
+        "(p | q) & (q | p)",
+        "duplicate_clauses",
+        &mut raa_tt_grammar,
+    )?;
+    let proposition: Proposition =
+        (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+    assert_eq!("(p | q)", proposition.to_cnf()?.to_string());
+
+    // Auxiliary variables don't clash with the variables of the proposition
+    let proposition = Proposition::Conjunction(Conjunction {
+        left: Box::new("t1".into()),
+        right: Box::new("t2".into()),
+    });
+    assert_eq!(
+        "(!t_1 | t1) & (!t_1 | t2) & (t_1 | !t1 | !t2) & (t_1)",
+        proposition.to_tseitin_cnf()?.to_string()
+    );
+    assert!(matches!(
+        Proposition::Void.to_cnf(),
+        Err(RaaError::VoidExpression)
+    ));
+    Ok(())
+}
+
+#[test]
+fn negation_and_disjunctive_normal_forms() -> Result<()> {
+    // Negations apply to atoms only and there are no implications
+    fn is_nnf(proposition: &Proposition) -> bool {
+        match proposition {
+            Proposition::Constant(_) | Proposition::Atom(_) => true,
+            Proposition::Negation(Negation { inner }) => matches!(**inner, Proposition::Atom(_)),
+            Proposition::Conjunction(Conjunction { left, right })
+            | Proposition::Disjunction(Disjunction { left, right }) => {
+                is_nnf(left) && is_nnf(right)
+            }
+            _ => false,
+        }
+    }
+    // No disjunction below a conjunction
+    fn is_dnf(proposition: &Proposition) -> bool {
+        match proposition {
+            Proposition::Disjunction(Disjunction { left, right }) => is_dnf(left) && is_dnf(right),
+            Proposition::Conjunction(Conjunction { left, right }) => {
+                !matches!(**left, Proposition::Disjunction(_))
+                    && !matches!(**right, Proposition::Disjunction(_))
+                    && is_dnf(left)
+                    && is_dnf(right)
+            }
+            _ => is_nnf(proposition),
+        }
+    }
+    let parse_proposition = |input: &str| -> Result<Proposition> {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        parse(input, "normal_forms", &mut raa_tt_grammar)?;
+        Ok((&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into())
+    };
+    for (p, _) in PROPOSITIONS {
+        let proposition = parse_proposition(p)?;
+        let nnf = proposition.to_nnf()?;
+        let dnf = proposition.to_dnf()?;
+        assert!(is_nnf(&nnf), "{p}: {nnf}");
+        assert!(is_dnf(&dnf), "{p}: {dnf}");
+        assert_eq!(nnf, parse_proposition(&nnf.to_string())?, "{p}");
+        assert_eq!(dnf, parse_proposition(&dnf.to_string())?, "{p}");
+
+        let variables = proposition.to_cnf()?.variables;
+        for bits in 0..1u32 << variables.len() {
+            let assignment = variables
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (v.clone(), bits & (1 << i) != 0))
+                .collect();
+            let value = proposition.evaluate(&assignment)?;
+            assert_eq!(value, nnf.evaluate(&assignment)?, "{p}");
+            assert_eq!(value, dnf.evaluate(&assignment)?, "{p}");
+        }
+    }
+
+    assert_eq!(
+        "((a & b) | (!a & !b))",
+        parse_proposition("a <-> b")?.to_nnf()?.to_string()
+    );
+    assert_eq!(
+        "((a & !b) | (!a & b))",
+        parse_proposition("!(a <-> b)")?.to_nnf()?.to_string()
+    );
+    assert_eq!("F", parse_proposition("p & !p")?.to_dnf()?.to_string());
+    Ok(())
+}
+
+#[test]
+fn dimacs_import_and_export() -> Result<()> {
+    for (i, (p, r)) in PROPOSITIONS.iter().enumerate() {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        let file_name = format!("example_{i}");
+        parse(p, &file_name, &mut raa_tt_grammar)?;
+        let proposition: Proposition =
+            (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+
+        // The variable names survive the round trip, auxiliary variables get new names
+        let tseitin = proposition.to_tseitin_cnf()?;
+        let read = ClauseSet::from_dimacs(&tseitin.to_dimacs())?;
+        assert_eq!(tseitin.clauses, read.clauses, "{p}");
+        assert_eq!(tseitin.variable_count, read.variables.len(), "{p}");
+        assert_eq!(tseitin.variables, read.variables[..tseitin.variables.len()]);
+
+        let cnf = proposition.to_cnf()?;
+        assert_eq!(cnf, ClauseSet::from_dimacs(&cnf.to_dimacs())?, "{p}");
+        if *r != ProveResult::Proven {
+            assert_eq!(
+                *r,
+                CdclSolver::new().classify(&cnf.to_proposition())?,
+                "{p}"
+            );
+        }
+    }
+
+    // Comments, clauses spanning lines and the SATLIB end marker
+    let input = "c example\nc var 2 q\np cnf 3 2\n1 -2\n 0 -3\n%\n0\n";
+    let clause_set = ClauseSet::from_dimacs(input)?;
+    assert_eq!(vec!["x1", "q", "x3"], clause_set.variables);
+    assert_eq!("(x1 | !q) & (!x3)", clause_set.to_string());
+
+    // Operators and constants can't be parsed as variables and get default names
+    let clause_set = ClauseSet {
+        variables: vec!["and".to_owned(), "p".to_owned(), "F".to_owned()],
+        variable_count: 3,
+        clauses: vec![vec![Literal::new(0, true), Literal::new(2, false)]],
+    };
+    let read = ClauseSet::from_dimacs(&clause_set.to_dimacs())?;
+    assert_eq!(vec!["x1", "p", "x3"], read.variables);
+    assert_eq!(clause_set.clauses, read.clauses);
+    let proposition = read.to_proposition();
+    let input = proposition.to_string();
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse(&input, "reserved_words", &mut raa_tt_grammar)?;
+    let parsed: Proposition =
+        (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+    assert_eq!(proposition, parsed);
+
+    for (input, line, message) in [
+        ("1 2 0\n", 1, "clause before the problem line"),
+        (
+            "p cnf 2 1\n1 3 0\n",
+            2,
+            "variable 3 exceeds the 2 declared variables",
+        ),
+        ("p cnf 2 2\n1 2 0\n", 2, "2 clauses declared, 1 found"),
+        (
+            "p cnf 3000000000000 0\n",
+            1,
+            "3000000000000 variables exceed the maximum of 1048576",
+        ),
+        (
+            "p cnf 2\n",
+            1,
+            "expected the problem line p cnf <variables> <clauses>",
+        ),
+        ("c var 1 P\np cnf 1 0\n", 1, "invalid variable name P"),
+        ("pcnf 1 0\n", 1, "clause before the problem line"),
+        (
+            "p\n",
+            1,
+            "expected the problem line p cnf <variables> <clauses>",
+        ),
+        (
+            "c var 1 p\nc var 2 p\np cnf 2 0\n",
+            3,
+            "variable name p used twice",
+        ),
+        ("c only a comment\n", 1, "missing problem line"),
+    ] {
+        match ClauseSet::from_dimacs(input) {
+            Err(RaaError::InvalidDimacs {
+                line: l,
+                message: m,
+            }) => {
+                assert_eq!((line, message), (l, m.as_str()), "{input}")
+            }
+            result => panic!("{input}: unexpected result {result:?}"),
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn equivalence_and_implication() -> Result<()> {
+    let prover = Prover::new();
+    let parse_proposition = |input: &str| -> Result<Proposition> {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        parse(input, "equivalence", &mut raa_tt_grammar)?;
+        Ok((&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into())
+    };
+    for (p, r) in PROPOSITIONS {
+        let proposition = parse_proposition(p)?;
+        let dnf = proposition.to_dnf()?;
+        assert_eq!(
+            Equivalence::Equivalent,
+            prover.equivalent(&proposition, &dnf)?
+        );
+
+        let negation = Proposition::Negation(Negation {
+            inner: Box::new(proposition.clone()),
+        });
+        let Equivalence::Different(assignment) = prover.equivalent(&proposition, &negation)? else {
+            panic!("{p} is equivalent to its negation");
+        };
+        assert_ne!(
+            proposition.evaluate(&assignment)?,
+            negation.evaluate(&assignment)?
+        );
+
+        // Only contradictions imply their negation
+        let validity = prover.implies(&proposition, &negation)?;
+        assert_eq!(
+            *r == ProveResult::Falsified,
+            validity == Validity::Valid,
+            "{p}"
+        );
+    }
+
+    for (left, right, equivalent) in [
+        ("p -> q", "!q -> !p", true),
+        ("!(p & q)", "!p | !q", true),
+        ("p <-> q", "(p -> q) & (q -> p)", true),
+        ("p -> q", "q -> p", false),
+        ("p & (q | r)", "p & q | r", false),
+        ("p", "p & (q | !q)", true),
+    ] {
+        let (left, right) = (parse_proposition(left)?, parse_proposition(right)?);
+        match prover.equivalent(&left, &right)? {
+            Equivalence::Equivalent => assert!(equivalent, "{left} {right}"),
+            Equivalence::Different(assignment) => {
+                assert!(!equivalent, "{left} {right}");
+                assert_ne!(left.evaluate(&assignment)?, right.evaluate(&assignment)?);
+            }
+        }
+    }
+
+    // p & q | r doesn't imply p & (q | r), r alone is enough
+    let Validity::Invalid(counterexample) = prover.implies(
+        &parse_proposition("p & q | r")?,
+        &parse_proposition("p & (q | r)")?,
+    )?
+    else {
+        panic!("The implication should not hold");
+    };
+    assert_eq!(Some(false), counterexample.get("p"));
+    assert_eq!(Some(true), counterexample.get("r"));
+    Ok(())
+}
+
+#[test]
+fn model_enumeration() -> Result<()> {
+    let prover = Prover::new();
+    let table_generator = TableGenerator::new();
+    let (dpll, cdcl) = (DpllSolver::new(), CdclSolver::new());
+    for (i, (p, r)) in PROPOSITIONS.iter().enumerate() {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        let file_name = format!("example_{i}");
+        parse(p, &file_name, &mut raa_tt_grammar)?;
+        let proposition: Proposition =
+            (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+
+        // The models are the TRUE rows of the truth table
+        let truth_table = table_generator.generate_truth_table(&proposition)?;
+        let rows = truth_table
+            .lines
+            .iter()
+            .filter(|line| line.last() == Some(&true))
+            .count();
+        let expected = table_generator
+            .models(&proposition)
+            .map(|m| m.map(|m| m.to_string()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        assert_eq!(rows, expected.len(), "{p}");
+        assert_eq!(*r == ProveResult::Falsified, expected.is_empty(), "{p}");
+
+        for models in [
+            prover.models(&proposition),
+            Models::new(&dpll, &proposition),
+            Models::new(&cdcl, &proposition),
+            cdcl.models(&proposition),
+        ] {
+            let mut models = models
+                .map(|m| m.map(|m| m.to_string()))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            models.sort();
+            let mut expected = expected.clone();
+            expected.sort();
+            assert_eq!(expected, models, "{p}");
+        }
+    }
+
+    // p | q projected onto q and a variable that doesn't occur in it
+    let proposition = Proposition::Disjunction(Disjunction {
+        left: Box::new("p".into()),
+        right: Box::new("q".into()),
+    });
+    for models in [
+        table_generator.models(&proposition),
+        prover.models(&proposition),
+        Models::new(&cdcl, &proposition),
+        Models::new(&table_generator, &proposition),
+        cdcl.models(&proposition),
+    ] {
+        let mut models = models
+            .project(["q", "r"])
+            .map(|m| m.map(|m| m.to_string()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        models.sort();
+        assert_eq!(
+            vec![
+                "q = F, r = F",
+                "q = F, r = T",
+                "q = T, r = F",
+                "q = T, r = T"
+            ],
+            models
+        );
+    }
+    assert_eq!(2, prover.models(&proposition).limit(2).count());
+    assert_eq!(1, prover.models(&proposition).project(["x"; 0]).count());
+
+    // The lazy enumeration finds the first models of a proposition with many variables
+    let proposition = create_disjunctive_proposition_with_variables(40);
+    assert_eq!(5, Models::new(&cdcl, &proposition).limit(5).count());
+    assert_eq!(5, cdcl.models(&proposition).limit(5).count());
+    assert_eq!(
+        1,
+        Models::new(&cdcl, &create_proposition_with_variables(40)).count()
+    );
+    // The combinations of the free variables of a branch are not limited to 64 variables
+    let variables = (0..70).map(|i| format!("x{i:02}")).collect::<Vec<_>>();
+    let models = prover
+        .models(&Proposition::from("p"))
+        .project(variables)
+        .limit(3)
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let last_values = |model: &Assignment| (model.get("x68"), model.get("x69"));
+    assert_eq!(
+        vec![
+            (Some(false), Some(false)),
+            (Some(false), Some(true)),
+            (Some(true), Some(false))
+        ],
+        models.iter().map(last_values).collect::<Vec<_>>()
+    );
+    let mut models = table_generator.models(&proposition);
+    assert!(matches!(
+        models.next(),
+        Some(Err(RaaError::TooManyVariables { .. }))
+    ));
+    assert!(models.next().is_none());
+
+    // Each model adds a single blocking clause to the clauses of the proposition
+    let proposition = create_disjunctive_proposition_with_variables(8);
+    assert_eq!(255, Models::new(&dpll, &proposition).count());
+    let proposition = create_disjunctive_proposition_with_variables(10);
+    assert_eq!(1023, cdcl.models(&proposition).count());
+    Ok(())
+}
+
+#[test]
+fn model_counting() -> Result<()> {
+    let table_generator = TableGenerator::new();
+    for (i, (p, _)) in PROPOSITIONS.iter().enumerate() {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        let file_name = format!("example_{i}");
+        parse(p, &file_name, &mut raa_tt_grammar)?;
+        let proposition: Proposition =
+            (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into();
+
+        // The count is the number of TRUE rows of the truth table
+        let truth_table = table_generator.generate_truth_table(&proposition)?;
+        let rows = truth_table
+            .lines
+            .iter()
+            .filter(|line| line.last() == Some(&true))
+            .count();
+        assert_eq!(rows as u128, proposition.count_models()?, "{p}");
+        assert_eq!(rows as u128, proposition.to_cnf()?.count_models()?, "{p}");
+    }
+
+    // Far beyond the limit of truth tables
+    let conjunction = create_proposition_with_variables(100);
+    assert_eq!(1, conjunction.count_models()?);
+    let disjunction = create_disjunctive_proposition_with_variables(100);
+    assert_eq!((1 << 100) - 1, disjunction.count_models()?);
+
+    // Two independent parts with 3 models each
+    let clause_set = ClauseSet::from_dimacs("p cnf 5 2\n1 2 0\n-3 -4 0\n")?;
+    assert_eq!(18, clause_set.count_models()?);
+
+    // Tautological and empty clauses
+    let clause_set = ClauseSet::from_dimacs("p cnf 2 1\n1 -1 0\n")?;
+    assert_eq!(4, clause_set.count_models()?);
+    let clause_set = ClauseSet::from_dimacs("p cnf 2 2\n1 0\n-1 0\n")?;
+    assert_eq!(0, clause_set.count_models()?);
+
+    // 2^200 - 1 models overflow
+    let disjunction = create_disjunctive_proposition_with_variables(200);
+    assert!(matches!(
+        disjunction.count_models(),
+        Err(RaaError::TooManyModels { .. })
+    ));
+    // but a contradiction with as many variables has none
+    let contradiction = Proposition::Conjunction(Conjunction {
+        left: Box::new(create_disjunctive_proposition_with_variables(200)),
+        right: Box::new(Proposition::Negation(Negation {
+            inner: Box::new(create_disjunctive_proposition_with_variables(200)),
+        })),
+    });
+    assert_eq!(0, contradiction.count_models()?);
+    Ok(())
+}
+
+#[test]
+fn boolean_constants() -> Result<()> {
+    let parse_proposition = |input: &str| -> Result<Proposition> {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        parse(input, "constants", &mut raa_tt_grammar)?;
+        Ok((&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into())
+    };
+    for (input, value) in [
+        ("T", true),
+        ("⊤", true),
+        ("true", true),
+        ("F", false),
+        ("⊥", false),
+        ("false", false),
+    ] {
+        assert_eq!(Proposition::Constant(value), parse_proposition(input)?);
+    }
+    // Longer names are still variables
+    assert_eq!(
+        Proposition::Atom("trueish".into()),
+        parse_proposition("trueish")?
+    );
+    assert_eq!("(p -> F)", parse_proposition("p -> ⊥")?.to_string());
+
+    // F closes its branch on its own
+    let prover = Prover::new();
+    let proof = prover.prove_with_tableaux(&parse_proposition("p & F")?)?;
+    assert_eq!(ProveResult::Falsified, proof.result);
+    let tableau = proof.tableau();
+    assert!(tableau.is_closed());
+    assert!(tableau.to_string().contains("✖ (3)"));
+    assert_eq!(ProveResult::Proven, prover.prove(&parse_proposition("T")?)?);
+    assert_eq!(
+        ProveResult::Falsified,
+        prover.prove(&parse_proposition("F")?)?
+    );
+    assert_eq!(
+        Validity::Valid,
+        prover.entails(&[parse_proposition("F")?], &"p".into())?
+    );
+
+    // Normal forms and clause sets
+    let tautology = parse_proposition("p | T")?;
+    assert!(tautology.to_cnf()?.clauses.is_empty());
+    assert_eq!(
+        Proposition::Constant(true),
+        tautology.to_cnf()?.to_proposition()
+    );
+    assert_eq!("T", tautology.to_dnf()?.to_string());
+    let contradiction = parse_proposition("p & F")?;
+    assert_eq!("⊥", contradiction.to_cnf()?.to_string());
+    assert_eq!(
+        Proposition::Constant(false),
+        contradiction.to_cnf()?.to_proposition()
+    );
+    assert_eq!("F", contradiction.to_dnf()?.to_string());
+    assert_eq!(
+        "(!p | F)",
+        parse_proposition("p -> F")?.to_nnf()?.to_string()
+    );
+    assert_eq!("T", parse_proposition("!F")?.to_nnf()?.to_string());
+
+    // Evaluation and counting
+    assert!(parse_proposition("T -> (F -> p)")?.evaluate(&Default::default())?);
+    assert_eq!(1, parse_proposition("T")?.count_models()?);
+    assert_eq!(0, parse_proposition("F")?.count_models()?);
+    assert_eq!(2, parse_proposition("p | true")?.count_models()?);
+    let (dpll, cdcl) = (DpllSolver::new(), CdclSolver::new());
+    for decider in [&dpll as &dyn Decider, &cdcl] {
+        assert!(decider.find_model(&contradiction)?.is_none());
+        assert!(decider.find_model(&tautology)?.is_some());
+    }
+    Ok(())
+}
+
+#[test]
+fn exclusive_disjunction_and_denials() -> Result<()> {
+    let parse_proposition = |input: &str| -> Result<Proposition> {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        parse(input, "connectives", &mut raa_tt_grammar)?;
+        Ok((&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into())
+    };
+    // Precedence: ! > & NAND > ^ > | NOR > -> > <->
+    for (input, expected) in [
+        ("p & q ^ r", "((p & q) ^ r)"),
+        ("p ^ q | r", "((p ^ q) | r)"),
+        ("p NAND q ^ r NOR s", "(((p NAND q) ^ r) NOR s)"),
+        ("p | q NOR r", "((p | q) NOR r)"),
+        ("p & q NAND r", "((p & q) NAND r)"),
+        ("!p ^ q -> r", "((!p ^ q) -> r)"),
+        ("p ^ q ^ r", "((p ^ q) ^ r)"),
+    ] {
+        let proposition = parse_proposition(input)?;
+        assert_eq!(expected, proposition.to_string(), "{input}");
+        // Display round-trips through the parser
+        assert_eq!(proposition, parse_proposition(&proposition.to_string())?);
+    }
+
+    // Truth tables of the connectives in the row order F F, F T, T F, T T
+    let table_generator = TableGenerator::new();
+    for (input, expected) in [
+        ("p ^ q", [false, true, true, false]),
+        ("p NAND q", [true, true, true, false]),
+        ("p NOR q", [true, false, false, false]),
+    ] {
+        let truth_table = table_generator.generate_truth_table(&parse_proposition(input)?)?;
+        let values = truth_table
+            .lines
+            .iter()
+            .map(|line| line[2])
+            .collect::<Vec<_>>();
+        assert_eq!(expected.to_vec(), values, "{input}");
+    }
+
+    // Each rule and its negated form
+    let prover = Prover::new();
+    for (input, rule) in [
+        ("p ^ q", Rule::ExclusiveDisjunction),
+        ("!(p ^ q)", Rule::NegatedExclusiveDisjunction),
+        ("p NAND q", Rule::AlternativeDenial),
+        ("!(p NAND q)", Rule::NegatedAlternativeDenial),
+        ("p NOR q", Rule::JointDenial),
+        ("!(p NOR q)", Rule::NegatedJointDenial),
+    ] {
+        // The truth tree of the argument starts with the proposition itself
+        let (validity, stats) = prover
+            .entails_with_stats(&[parse_proposition(input)?], &Proposition::Constant(false))?;
+        assert!(matches!(validity, Validity::Invalid(_)), "{input}");
+        assert!(stats.rule_applications.contains_key(&rule), "{input}");
+    }
+    assert_eq!(
+        Validity::Valid,
+        prover.entails(
+            &[parse_proposition("p ^ q")?, "p".into()],
+            &parse_proposition("!q")?
+        )?
+    );
+    Ok(())
+}
+
+#[test]
+fn alternative_notations() -> Result<()> {
+    let parse_statements = |input: &str| -> Result<Vec<Statement>> {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        parse(input, "notations", &mut raa_tt_grammar)?;
+        Ok(raa_tt_grammar
+            .raa_tt
+            .as_ref()
+            .unwrap()
+            .raa_tt_list
+            .iter()
+            .map(Statement::from)
+            .collect())
+    };
+    let parse_proposition = |input: &str| -> Result<Proposition> {
+        match &parse_statements(input)?[..] {
+            [Statement::Proposition(proposition)] => Ok(proposition.clone()),
+            statements => panic!("Expected a single proposition, found {statements:?}"),
+        }
+    };
+    let expected = parse_proposition("!(p & q) <-> (!p | !q) -> r")?;
+    for input in [
+        "¬(p ∧ q) ↔ (¬p ∨ ¬q) → r",
+        "~(p /\\ q) <=> (~p \\/ ~q) => r",
+        "not (p and q) <-> (not p or not q) -> r",
+        "¬(p /\\ q) <-> (not p ∨ ~q) => r",
+    ] {
+        assert_eq!(expected, parse_proposition(input)?, "{input}");
+    }
+    assert_eq!(
+        parse_proposition("(p ^ q NAND r) NOR T")?,
+        parse_proposition("(p ⊕ q ↑ r) ↓ ⊤")?
+    );
+    assert_eq!(
+        parse_proposition("(p ^ q NAND r) NOR T")?,
+        parse_proposition("(p xor q nand r) nor true")?
+    );
+    // Longer names are still variables
+    assert_eq!(
+        Proposition::Atom("notion".into()),
+        parse_proposition("notion")?
+    );
+    assert_eq!(
+        "(android | order)",
+        parse_proposition("android or order")?.to_string()
+    );
+    assert_eq!(
+        "(norm NOR xor_gate)",
+        parse_proposition("norm nor xor_gate")?.to_string()
+    );
+
+    assert_eq!(
+        "(¬(p ∧ q) ↔ ((¬p ∨ ¬q) → r))",
+        expected.with_notation(Notation::Unicode).to_string()
+    );
+    assert_eq!(
+        "(~(p /\\ q) <=> ((~p \\/ ~q) => r))",
+        expected.with_notation(Notation::Slashes).to_string()
+    );
+    assert_eq!(
+        "(not (p and q) <-> ((not p or not q) -> r))",
+        expected.with_notation(Notation::Words).to_string()
+    );
+    assert_eq!(
+        expected.to_string(),
+        expected.with_notation(Notation::Ascii).to_string()
+    );
+
+    // The output of each notation can be parsed again
+    for notation in [
+        Notation::Ascii,
+        Notation::Slashes,
+        Notation::Unicode,
+        Notation::Words,
+    ] {
+        for (input, _) in PROPOSITIONS {
+            let proposition = parse_proposition(input)?;
+            let output = proposition.with_notation(notation).to_string();
+            assert_eq!(proposition, parse_proposition(&output)?, "{output}");
+        }
+    }
+
+    let [Statement::Argument(argument)] = &parse_statements("p → q, p ⊢ q")?[..] else {
+        panic!("Expected an argument");
+    };
+    assert_eq!("(p -> q), p |- q", argument.to_string());
+    assert_eq!(
+        "(p → q), p ⊢ q",
+        argument.with_notation(Notation::Unicode).to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn pretty_printing() -> Result<()> {
+    let parse_proposition = |input: &str| -> Result<Proposition> {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        parse(input, "pretty", &mut raa_tt_grammar)?;
+        Ok((&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into())
+    };
+    let printer = PrettyPrinter::new();
+    for (input, expected) in [
+        ("((p & q) -> p)", "p & q -> p"),
+        ("(p -> q) -> r", "(p -> q) -> r"),
+        ("p -> (q -> r)", "p -> (q -> r)"),
+        ("(p | q) & !(r | s)", "(p | q) & !(r | s)"),
+        ("p | (q & r)", "p | q & r"),
+        ("(p ^ q) | (r NOR s)", "p ^ q | (r NOR s)"),
+        ("p & (q NAND r)", "p & (q NAND r)"),
+        ("!(!p) <-> ((F))", "!!p <-> F"),
+    ] {
+        assert_eq!(
+            expected,
+            printer.print(&parse_proposition(input)?),
+            "{input}"
+        );
+    }
+    assert_eq!(
+        "¬(p ∧ q) ↔ ¬p ∨ ¬q",
+        PrettyPrinter {
+            notation: Notation::Unicode,
+            ..Default::default()
+        }
+        .print(&parse_proposition("!(p & q) <-> !p | !q")?)
+    );
+
+    // Long propositions are broken before the operators and indented in parentheses
+    let proposition = parse_proposition("(p -> q) & (q -> r) & (r -> s) -> (p -> s)")?;
+    let printer = PrettyPrinter {
+        width: Some(20),
+        indent: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        "(p -> q)\n& (q -> r)\n& (r -> s)\n-> (p -> s)",
+        printer.print(&proposition)
+    );
+    let printer = PrettyPrinter {
+        width: Some(10),
+        indent: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        "(p -> q)\n& (q -> r)\n& (r -> s)\n-> (\n  p -> s\n)",
+        printer.print(&proposition)
+    );
+
+    // The output is parsed into the same proposition again
+    for notation in [
+        Notation::Ascii,
+        Notation::Slashes,
+        Notation::Unicode,
+        Notation::Words,
+    ] {
+        for width in [None, Some(40), Some(12), Some(1)] {
+            let printer = PrettyPrinter {
+                notation,
+                width,
+                ..Default::default()
+            };
+            for (input, _) in PROPOSITIONS {
+                let proposition = parse_proposition(input)?;
+                let output = printer.print(&proposition);
+                assert_eq!(proposition, parse_proposition(&output)?, "{output}");
+                if width == Some(40) {
+                    assert!(output.lines().all(|line| line.chars().count() <= 40));
+                }
+            }
+        }
+    }
+
+    // Truth tables keep the fully parenthesized propositions
+    let truth_table = TableGenerator::new().generate_truth_table(&proposition)?;
+    assert_eq!(
+        "((((p -> q) & (q -> r)) & (r -> s)) -> (p -> s))",
+        truth_table.header.last().unwrap()
+    );
+    Ok(())
+}
+
+#[test]
+fn implication_associativity() -> Result<()> {
+    let parse_with = |input: &str, implication| -> Result<(Proposition, Vec<String>)> {
+        let mut raa_tt_grammar = RaaTtGrammar::with_associativity(implication);
+        parse(input, "chains", &mut raa_tt_grammar)?;
+        let [Statement::Proposition(proposition)] = &raa_tt_grammar.statements()[..] else {
+            panic!("Expected a single proposition in {input}");
+        };
+        Ok((proposition.clone(), raa_tt_grammar.warnings))
+    };
+    let (left, warnings) = parse_with("a -> b -> c -> d", Associativity::Left)?;
+    assert_eq!("(((a -> b) -> c) -> d)", left.to_string());
+    assert_eq!(
+        vec![
+            "chains:1:8: An implication chain without parentheses like a -> b -> c is read as (a -> b) -> c"
+        ],
+        warnings
+    );
+    let (right, warnings) = parse_with("a -> b -> c -> d", Associativity::Right)?;
+    assert_eq!("(a -> (b -> (c -> d)))", right.to_string());
+    assert_eq!(1, warnings.len());
+    assert!(warnings[0].ends_with("is read as a -> (b -> c)"));
+
+    // The default of the grammar and the conversions is left associative
+    let mut raa_tt_grammar = RaaTtGrammar::new();
+    parse("a -> b -> c -> d", "chains", &mut raa_tt_grammar)?;
+    assert_eq!(
+        left,
+        (&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into()
+    );
+
+    // Other operators and explicit parentheses are not affected
+    for input in [
+        "(a -> b) -> c",
+        "a -> (b -> c)",
+        "a & b & c <-> a",
+        "a -> b",
+    ] {
+        let (left, warnings) = parse_with(input, Associativity::Left)?;
+        assert!(warnings.is_empty(), "{input}");
+        let (right, warnings) = parse_with(input, Associativity::Right)?;
+        assert!(warnings.is_empty(), "{input}");
+        assert_eq!(left, right, "{input}");
+    }
+    let (proposition, warnings) =
+        parse_with("(a -> b -> c) & (c -> d -> e)", Associativity::Right)?;
+    assert_eq!(
+        "((a -> (b -> c)) & (c -> (d -> e)))",
+        proposition.to_string()
+    );
+    assert_eq!(2, warnings.len());
+
+    let prover = Prover::new();
+    assert_eq!(
+        ProveResult::Contingent,
+        prover.prove(&parse_with("a -> b -> a", Associativity::Left)?.0)?
+    );
+    assert_eq!(
+        ProveResult::Proven,
+        prover.prove(&parse_with("a -> b -> a", Associativity::Right)?.0)?
+    );
+
+    // The pretty printer keeps the parentheses of nested implications, so its output is read
+    // the same with both associativities and without warnings
+    assert_eq!("a -> (b -> (c -> d))", PrettyPrinter::new().print(&right));
+    assert_eq!("((a -> b) -> c) -> d", PrettyPrinter::new().print(&left));
+    for width in [None, Some(12), Some(1)] {
+        let printer = PrettyPrinter {
+            width,
+            ..Default::default()
+        };
+        for (input, _) in PROPOSITIONS {
+            for implication in [Associativity::Left, Associativity::Right] {
+                let (proposition, _) = parse_with(input, implication)?;
+                let output = printer.print(&proposition);
+                for associativity in [Associativity::Left, Associativity::Right] {
+                    let (parsed, warnings) = parse_with(&output, associativity)?;
+                    assert_eq!(proposition, parsed, "{output}");
+                    assert!(warnings.is_empty(), "{output}");
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn cdcl_large_formulas() -> Result<()> {
+    let solver = CdclSolver::new();
+    let parse_proposition = |input: &str| -> Result<Proposition> {
+        let mut raa_tt_grammar = RaaTtGrammar::new();
+        parse(input, "cdcl", &mut raa_tt_grammar)?;
+        Ok((&raa_tt_grammar.raa_tt.as_ref().unwrap().raa_tt_list[0].biconditional).into())
+    };
+
+    // Hypothetical syllogism over a chain of 300 implications
+    let chain = (0..300)
+        .map(|i| format!("(p{i} -> p{})", i + 1))
+        .collect::<Vec<_>>()
+        .join(" & ");
+    let proposition = parse_proposition(&format!("{chain} -> (p0 -> p300)"))?;
+    assert_eq!(ProveResult::Proven, solver.classify(&proposition)?);
+    let proposition = parse_proposition(&format!("{chain} -> (p300 -> p0)"))?;
+    assert_eq!(ProveResult::Contingent, solver.classify(&proposition)?);
+
+    // Exactly one of 200 variables is true and it is p199
+    let at_least_one = (0..200)
+        .map(|i| format!("p{i}"))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    let at_most_one = (0..199)
+        .map(|i| format!("!(p{i} & p{})", i + 1))
+        .collect::<Vec<_>>()
+        .join(" & ");
+    let proposition = parse_proposition(&format!(
+        "({at_least_one}) & {at_most_one} & !p0 & !p1 & (p0 | p1 | p199)"
+    ))?;
+    let model = solver.find_model(&proposition)?.unwrap();
+    assert_eq!(200, model.len());
+    assert_eq!(Some(true), model.get("p199"));
+    assert!(proposition.evaluate(&model)?);
+    Ok(())
+}
+
 // Helper functions for variable count testing
 
 /// Creates a proposition with exactly `count` unique variables using a chain of conjunctions.